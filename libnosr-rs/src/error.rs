@@ -3,7 +3,7 @@
 //! This module provides error types that track where parsing failures occur,
 //! helping developers understand what went wrong and where.
 
-use crate::span::Span;
+use crate::span::{SourceMap, Span};
 use std::fmt;
 
 /// Result type for nosr operations.
@@ -26,6 +26,51 @@ impl Error {
     pub fn new(kind: ErrorKind, span: Span) -> Self {
         Self { kind, span }
     }
+
+    /// Render this error as a human-readable diagnostic: the 1-based
+    /// line/column, the offending source line, and a caret/underline
+    /// spanning the error's span, in the style of rustc's diagnostics.
+    ///
+    /// A span that crosses a newline has its underline clamped to the
+    /// first line; an empty span renders a single caret.
+    ///
+    /// Builds a fresh [`SourceMap`] for this one lookup; when rendering
+    /// several errors against the same document (e.g. the `Vec<Error>`
+    /// from `table_recover`), build a `SourceMap` once and call
+    /// [`Error::render_with`] for each instead.
+    pub fn render(&self, source: &str) -> String {
+        self.render_with(&SourceMap::new(source), source)
+    }
+
+    /// Like [`Error::render`], but resolves the span via a precomputed
+    /// [`SourceMap`] instead of rebuilding the line-start table from
+    /// scratch. `map` must have been built from `source`.
+    pub fn render_with(&self, map: &SourceMap, source: &str) -> String {
+        let loc = map.location(source, self.span);
+        let line_chars = loc.line_text.chars().count();
+
+        let underline_len = if self.span.len == 0 {
+            1
+        } else {
+            self.span
+                .extract(source)
+                .chars()
+                .take_while(|&ch| ch != '\n')
+                .count()
+                .max(1)
+        }
+        .min(line_chars.saturating_sub(loc.col - 1).max(1));
+
+        format!(
+            "{} at {}:{}\n{}\n{}{}",
+            self.kind,
+            loc.line,
+            loc.col,
+            loc.line_text,
+            " ".repeat(loc.col - 1),
+            "^".repeat(underline_len)
+        )
+    }
 }
 
 /// The kind of error that occurred during parsing or navigation.
@@ -40,22 +85,55 @@ pub enum ErrorKind {
     ExpectedChar(char),
     /// Invalid escape sequence in a string
     InvalidEscape(char),
+    /// A lone trailing backslash immediately before the closing quote, with
+    /// no escape character following it
+    UnterminatedEscape,
+    /// A malformed `\u{...}` Unicode escape: an empty brace, a non-hex
+    /// digit, an unclosed brace, a code point beyond U+10FFFF, or a UTF-16
+    /// surrogate
+    InvalidUnicodeEscape,
+    /// A malformed `\xNN` hex escape: not exactly two hex digits, or a
+    /// value of 0x80 or above (only ASCII is representable this way)
+    InvalidHexEscape,
+    /// A confusable Unicode look-alike was found where a structural
+    /// character was expected (e.g. a fullwidth colon instead of `:`)
+    ConfusableChar {
+        /// The character that was actually found
+        found: char,
+        /// The ASCII structural character it is likely meant to be
+        suggested: char,
+    },
     /// Unclosed string literal
     UnclosedString,
     /// Unclosed block comment
     UnclosedComment,
+    /// Two or more delimiters (commas) in a row inside a table or vector,
+    /// without an intervening key-value pair or element
+    ConsecutiveDelimiters,
     /// Expected a table but found something else
     NotATable,
     /// Expected a vector but found something else
     NotAVector,
     /// Expected a scalar value but found something else
     NotAScalar,
-    /// Key not found in table
-    KeyNotFound(String),
+    /// Key not found in table, optionally with the closest existing key by
+    /// edit distance (e.g. "did you mean 'host'?" for a `hots` typo)
+    KeyNotFound {
+        /// The key that was looked up
+        key: String,
+        /// The closest existing key, if one was within the suggestion threshold
+        suggestion: Option<String>,
+    },
     /// Index out of bounds in vector
     IndexOutOfBounds(usize),
     /// Failed to parse value as requested type
     ParseError(String),
+    /// A `rational` scalar had a denominator of zero
+    ZeroDenominator,
+    /// A bare scalar used as a table key is not a legal identifier (after
+    /// NFC normalization) under the Unicode XID start/continue character
+    /// classes
+    InvalidKey(String),
 }
 
 impl fmt::Display for Error {
@@ -71,14 +149,34 @@ impl fmt::Display for ErrorKind {
             ErrorKind::UnexpectedChar(ch) => write!(f, "unexpected character '{}'", ch),
             ErrorKind::ExpectedChar(ch) => write!(f, "expected '{}'", ch),
             ErrorKind::InvalidEscape(ch) => write!(f, "invalid escape sequence '\\{}'", ch),
+            ErrorKind::UnterminatedEscape => {
+                write!(f, "trailing backslash with no escape character")
+            }
+            ErrorKind::InvalidUnicodeEscape => write!(f, "invalid unicode escape sequence"),
+            ErrorKind::InvalidHexEscape => write!(f, "invalid hex escape sequence"),
+            ErrorKind::ConfusableChar { found, suggested } => write!(
+                f,
+                "found '{}' (U+{:04X}), did you mean '{}'?",
+                found, *found as u32, suggested
+            ),
             ErrorKind::UnclosedString => write!(f, "unclosed string literal"),
             ErrorKind::UnclosedComment => write!(f, "unclosed block comment"),
+            ErrorKind::ConsecutiveDelimiters => {
+                write!(f, "consecutive delimiters without an intervening value")
+            }
             ErrorKind::NotATable => write!(f, "expected a table"),
             ErrorKind::NotAVector => write!(f, "expected a vector"),
             ErrorKind::NotAScalar => write!(f, "expected a scalar value"),
-            ErrorKind::KeyNotFound(key) => write!(f, "key '{}' not found", key),
+            ErrorKind::KeyNotFound { key, suggestion } => match suggestion {
+                Some(suggestion) => {
+                    write!(f, "key '{}' not found, did you mean '{}'?", key, suggestion)
+                }
+                None => write!(f, "key '{}' not found", key),
+            },
             ErrorKind::IndexOutOfBounds(idx) => write!(f, "index {} out of bounds", idx),
             ErrorKind::ParseError(msg) => write!(f, "parse error: {}", msg),
+            ErrorKind::ZeroDenominator => write!(f, "rational denominator cannot be zero"),
+            ErrorKind::InvalidKey(key) => write!(f, "invalid key '{}': not a valid identifier", key),
         }
     }
 }
@@ -96,4 +194,49 @@ mod tests {
         assert!(msg.contains("unexpected end of input"));
         assert!(msg.contains("42"));
     }
+
+    #[test]
+    fn render_points_at_the_offending_token() {
+        let source = "{ a: b c }";
+        let err = Error::new(ErrorKind::UnexpectedChar('c'), Span::new(7, 1));
+        let rendered = err.render(source);
+        assert!(rendered.contains("1:8"));
+        assert!(rendered.contains(source));
+        assert!(rendered.ends_with("^"));
+    }
+
+    #[test]
+    fn render_empty_span_is_single_caret() {
+        let err = Error::new(ErrorKind::UnexpectedEof, Span::new(5, 0));
+        let rendered = err.render("hello");
+        assert!(rendered.ends_with("^"));
+        assert!(!rendered.ends_with("^^"));
+    }
+
+    #[test]
+    fn render_clamps_underline_at_newline() {
+        let source = "ab\ncd";
+        let err = Error::new(ErrorKind::UnexpectedEof, Span::new(1, 4)); // "b\ncd"
+        let rendered = err.render(source);
+        let underline = rendered.lines().last().unwrap();
+        assert_eq!(underline.trim_start().len(), 1);
+    }
+
+    #[test]
+    fn render_with_map_matches_render() {
+        let source = "{ a: b c }";
+        let err = Error::new(ErrorKind::UnexpectedChar('c'), Span::new(7, 1));
+        let map = SourceMap::new(source);
+        assert_eq!(err.render_with(&map, source), err.render(source));
+    }
+
+    #[test]
+    fn render_with_map_reused_across_errors() {
+        let source = "a: :\nb: :";
+        let map = SourceMap::new(source);
+        let first = Error::new(ErrorKind::ExpectedChar(':'), Span::new(3, 1));
+        let second = Error::new(ErrorKind::ExpectedChar(':'), Span::new(8, 1));
+        assert!(first.render_with(&map, source).contains("1:4"));
+        assert!(second.render_with(&map, source).contains("2:4"));
+    }
 }
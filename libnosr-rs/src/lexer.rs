@@ -3,7 +3,7 @@
 //! The lexer breaks the input into tokens, handling:
 //! - Structural characters: `{`, `}`, `[`, `]`, `:`, `,`
 //! - String literals with escape sequences
-//! - Comments (line and block)
+//! - Comments (line, block, and `;;;` doc comments)
 //! - Whitespace
 //! - Scalar values (everything else)
 //!
@@ -12,8 +12,255 @@
 
 use crate::error::{Error, ErrorKind, Result};
 use crate::span::Span;
+use std::collections::VecDeque;
+
+/// Confusable Unicode code points mapped to the ASCII structural character
+/// they are most likely meant to stand in for, e.g. a pasted fullwidth
+/// colon where a `:` was intended. Consulted before an unexpected character
+/// at a delimiter position is swallowed into a scalar token.
+const CONFUSABLES: &[(char, char)] = &[
+    ('\u{FF1A}', ':'), // FULLWIDTH COLON
+    ('\u{FF0C}', ','), // FULLWIDTH COMMA
+    ('\u{FF3B}', '['), // FULLWIDTH LEFT SQUARE BRACKET
+    ('\u{FF3D}', ']'), // FULLWIDTH RIGHT SQUARE BRACKET
+    ('\u{FF5B}', '{'), // FULLWIDTH LEFT CURLY BRACKET
+    ('\u{FF5D}', '}'), // FULLWIDTH RIGHT CURLY BRACKET
+    ('\u{201C}', '"'), // LEFT DOUBLE QUOTATION MARK
+    ('\u{201D}', '"'), // RIGHT DOUBLE QUOTATION MARK
+];
+
+/// Look up the ASCII structural character a confusable Unicode code point is
+/// most likely meant to stand in for.
+fn confusable_ascii(ch: char) -> Option<char> {
+    CONFUSABLES
+        .iter()
+        .find(|&&(confusable, _)| confusable == ch)
+        .map(|&(_, ascii)| ascii)
+}
+
+/// NFC-normalize a bare scalar used as a table key and validate that it
+/// forms a legal identifier under the Unicode XID start/continue character
+/// classes, so visually identical keys with different Unicode compositions
+/// (precomposed vs. decomposed accents) compare and hash equal.
+///
+/// `start` is `raw`'s byte offset within the full source, so an
+/// [`ErrorKind::InvalidKey`] can point back at exactly the offending key
+/// rather than the whole table. Returns `Ok(None)` when normalization didn't
+/// change anything, so callers can keep borrowing `raw` directly in the
+/// common case - mirroring [`decode_escapes`]'s return convention.
+///
+/// Requires the `unicode-normalization` and `unicode-xid` crates as
+/// dependencies.
+pub(crate) fn validate_key(raw: &str, start: usize) -> Result<Option<String>> {
+    use unicode_normalization::UnicodeNormalization;
+    use unicode_xid::UnicodeXID;
+
+    let normalized: String = raw.nfc().collect();
+
+    let mut chars = normalized.chars();
+    let is_identifier = match chars.next() {
+        Some(first) => first.is_xid_start() && chars.all(|ch| ch.is_xid_continue()),
+        None => false,
+    };
+
+    if !is_identifier {
+        return Err(Error::new(
+            ErrorKind::InvalidKey(raw.to_string()),
+            Span::new(start, raw.len()),
+        ));
+    }
+
+    Ok(if normalized == raw { None } else { Some(normalized) })
+}
+
+/// Decode the escape sequences in a string literal's inner content (the
+/// text between the quotes), validating each one along the way.
+///
+/// `inner_start` is the inner content's byte offset within the full source,
+/// so errors can carry a span over just the offending escape rather than
+/// the whole string literal. Returns `Ok(None)` when no escapes were found,
+/// so callers can avoid allocating (and can keep borrowing `inner`
+/// directly) in the common case.
+///
+/// Recognizes `\\`, `\n`, `\t`, `\r`, `\:`, `\"`, `\[`, `\]`, `\{`, `\}`,
+/// two-digit `\xHH` hex escapes (ASCII only), and brace-delimited
+/// `\u{XXXX}` Unicode escapes (1-6 hex digits, rejecting surrogates and
+/// values above U+10FFFF).
+pub(crate) fn decode_escapes(inner: &str, inner_start: usize) -> Result<Option<String>> {
+    // Indexed rather than a plain char iterator since `\u{...}` and `\xNN`
+    // need variable-length lookahead.
+    let chars: Vec<(usize, char)> = inner.char_indices().collect();
+    let mut result: Option<String> = None;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (idx, ch) = chars[i];
+        if ch != '\\' {
+            if let Some(ref mut s) = result {
+                s.push(ch);
+            }
+            i += 1;
+            continue;
+        }
+
+        // Found an escape - initialize result if needed
+        let s = result.get_or_insert_with(|| String::from(&inner[..idx]));
+        i += 1;
+
+        let Some(&(esc_idx, esc_ch)) = chars.get(i) else {
+            return Err(Error::new(
+                ErrorKind::UnterminatedEscape,
+                Span::new(inner_start + idx, 1),
+            ));
+        };
+
+        match esc_ch {
+            '\\' => {
+                s.push('\\');
+                i += 1;
+            }
+            'n' => {
+                s.push('\n');
+                i += 1;
+            }
+            't' => {
+                s.push('\t');
+                i += 1;
+            }
+            'r' => {
+                s.push('\r');
+                i += 1;
+            }
+            ':' => {
+                s.push(':');
+                i += 1;
+            }
+            '"' => {
+                s.push('"');
+                i += 1;
+            }
+            '[' => {
+                s.push('[');
+                i += 1;
+            }
+            ']' => {
+                s.push(']');
+                i += 1;
+            }
+            '{' => {
+                s.push('{');
+                i += 1;
+            }
+            '}' => {
+                s.push('}');
+                i += 1;
+            }
+            'x' => {
+                i += 1; // past 'x'
+                let mut hex = String::with_capacity(2);
+                for _ in 0..2 {
+                    match chars.get(i) {
+                        Some(&(_, c)) if c.is_ascii_hexdigit() => {
+                            hex.push(c);
+                            i += 1;
+                        }
+                        _ => {
+                            let end = byte_end(&chars, i, inner.len());
+                            return Err(Error::new(
+                                ErrorKind::InvalidHexEscape,
+                                Span::new(inner_start + idx, end - idx),
+                            ));
+                        }
+                    }
+                }
+                let value = u8::from_str_radix(&hex, 16).expect("validated hex digits");
+                if value >= 0x80 {
+                    let end = byte_end(&chars, i, inner.len());
+                    return Err(Error::new(
+                        ErrorKind::InvalidHexEscape,
+                        Span::new(inner_start + idx, end - idx),
+                    ));
+                }
+                s.push(value as char);
+            }
+            'u' => {
+                i += 1; // past 'u'
+                if chars.get(i).map(|&(_, c)| c) != Some('{') {
+                    let end = byte_end(&chars, i, inner.len());
+                    return Err(Error::new(
+                        ErrorKind::InvalidUnicodeEscape,
+                        Span::new(inner_start + idx, end - idx),
+                    ));
+                }
+                i += 1; // past '{'
+
+                let mut hex = String::new();
+                loop {
+                    match chars.get(i) {
+                        Some(&(_, '}')) => break,
+                        Some(&(_, c)) if c.is_ascii_hexdigit() && hex.len() < 6 => {
+                            hex.push(c);
+                            i += 1;
+                        }
+                        _ => {
+                            let end = byte_end(&chars, i, inner.len());
+                            return Err(Error::new(
+                                ErrorKind::InvalidUnicodeEscape,
+                                Span::new(inner_start + idx, end - idx),
+                            ));
+                        }
+                    }
+                }
+
+                // The loop above only exits via `break` (closing brace
+                // found) or an early return, so `chars[i]` is `'}'` here.
+                let close_end = chars[i].0 + 1;
+                i += 1; // past '}'
+
+                let code_point = if hex.is_empty() {
+                    None
+                } else {
+                    u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .filter(|&cp| cp <= 0x10FFFF && !(0xD800..=0xDFFF).contains(&cp))
+                        .and_then(char::from_u32)
+                };
+
+                match code_point {
+                    Some(c) => s.push(c),
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidUnicodeEscape,
+                            Span::new(inner_start + idx, close_end - idx),
+                        ));
+                    }
+                }
+            }
+            other => {
+                let span_len = esc_idx - idx + other.len_utf8();
+                return Err(Error::new(
+                    ErrorKind::InvalidEscape(other),
+                    Span::new(inner_start + idx, span_len),
+                ));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Byte offset of `chars[i]` (or the end of the string if `i` is past the
+/// last char), used to compute an error span's end when an escape sequence
+/// is cut short.
+fn byte_end(chars: &[(usize, char)], i: usize, len: usize) -> usize {
+    chars.get(i).map(|&(byte, _)| byte).unwrap_or(len)
+}
 
 /// A token produced by the lexer.
+///
+/// Carries only its kind and span - decoding and escape validation happen
+/// lazily, only once a string's value is actually requested, via
+/// [`crate::node::text`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Token {
     /// The kind of token
@@ -47,21 +294,130 @@ pub enum TokenKind {
     Eof,
 }
 
+/// A pull-based source of input text.
+///
+/// Implementations report additional text as it becomes available and
+/// signal exhaustion once there is no more, letting [`Lexer`] tokenize a
+/// document that arrives incrementally (e.g. from a `Read`er or a chunked
+/// socket) instead of requiring the whole thing in memory as a single
+/// `&str` up front.
+///
+/// This only benefits [`Lexer`] and [`TokenStream`] directly.
+/// [`crate::node::Node`] and every function in [`crate::node`] still store
+/// and operate on a materialized `&str` (`Node::source`), so a document
+/// streamed through a custom `Input` is fully buffered in memory the moment
+/// it's wrapped in a [`crate::node::Node`] - incremental lexing doesn't
+/// carry through to the rest of the crate's API.
+pub trait Input {
+    /// Pull the next chunk of available text, appending it to `buf`.
+    ///
+    /// Returns the number of bytes appended. Returning `0` signals that the
+    /// input is exhausted and no more text will ever become available.
+    fn fill(&mut self, buf: &mut String) -> usize;
+}
+
+/// The [`Input`] that serves a complete in-memory string in a single chunk.
+///
+/// This is the thin adapter that lets [`Lexer::new`] keep accepting a plain
+/// `&str`, with none of the streaming machinery actually engaged.
+pub struct StrInput<'a>(Option<&'a str>);
+
+impl<'a> Input for StrInput<'a> {
+    fn fill(&mut self, buf: &mut String) -> usize {
+        match self.0.take() {
+            Some(s) => {
+                buf.push_str(s);
+                s.len()
+            }
+            None => 0,
+        }
+    }
+}
+
+/// Options controlling [`Lexer`] behavior.
+///
+/// Defaults preserve the strict, single-line-delimited tokenization the
+/// lexer has always done.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LexerOptions {
+    /// Suppress newlines that would otherwise delimit a value, so a
+    /// key-value pair or a bracketed list can wrap across multiple lines -
+    /// analogous to automatic semicolon insertion in other grammars, just
+    /// in reverse (automatic newline *elision*).
+    ///
+    /// A newline is suppressed when the previous significant token was one
+    /// of `:`, `,`, `{`, or `[`, or when the newline immediately precedes a
+    /// `}` or `]` (modulo further whitespace, newlines, and comments).
+    pub suppress_significant_newlines: bool,
+
+    /// NFC-normalize and validate bare scalar table keys as legal
+    /// identifiers (XID start/continue) via [`validate_key`], rejecting
+    /// anything else with [`ErrorKind::InvalidKey`].
+    ///
+    /// Quoted string keys are unaffected - quoting is how a key opts out of
+    /// identifier rules in the first place.
+    ///
+    /// Only [`crate::node::table_with_options`] reads this flag, for the keys
+    /// it parses; [`Lexer`] itself tokenizes the same way whether or not it
+    /// is set.
+    pub validate_keys: bool,
+}
+
 /// A lexer that tokenizes nosr input.
 ///
-/// The lexer maintains its position in the source and provides
-/// methods to peek at and consume tokens.
-pub struct Lexer<'a> {
-    /// The source text being lexed
-    source: &'a str,
-    /// Current byte position in the source
+/// Reads through a buffer that is refilled on demand rather than requiring
+/// the complete document up front: `peek`/`peek_at`/`consume` pull more text
+/// from the underlying [`Input`] only as needed. The buffer is never
+/// trimmed; it keeps every byte pulled so far. `Span`s are still absolute byte
+/// offsets into everything buffered so far, so `extract` keeps working once
+/// the buffer is fully materialized.
+pub struct Lexer<'a, I: Input = StrInput<'a>> {
+    /// The underlying pull-based source
+    input: I,
+    /// Every byte pulled from `input` so far
+    buffer: String,
+    /// Whether `input` has reported exhaustion
+    exhausted: bool,
+    /// Current byte position in `buffer`
     pos: usize,
+    /// Behavior flags for this lexer
+    options: LexerOptions,
+    /// The kind of the last non-comment, non-whitespace token returned,
+    /// consulted by [`LexerOptions::suppress_significant_newlines`]
+    last_significant: Option<TokenKind>,
+    _marker: std::marker::PhantomData<&'a ()>,
 }
 
-impl<'a> Lexer<'a> {
+impl<'a> Lexer<'a, StrInput<'a>> {
     /// Create a new lexer for the given source.
     pub fn new(source: &'a str) -> Self {
-        Self { source, pos: 0 }
+        Self::from_input(StrInput(Some(source)))
+    }
+
+    /// Create a new lexer for the given source with non-default options.
+    pub fn with_options(source: &'a str, options: LexerOptions) -> Self {
+        Self::from_input_with_options(StrInput(Some(source)), options)
+    }
+}
+
+impl<'a, I: Input> Lexer<'a, I> {
+    /// Create a new lexer over an arbitrary pull-based [`Input`].
+    pub fn from_input(input: I) -> Self {
+        Self::from_input_with_options(input, LexerOptions::default())
+    }
+
+    /// Create a new lexer over an arbitrary pull-based [`Input`] with
+    /// non-default options.
+    pub fn from_input_with_options(input: I, options: LexerOptions) -> Self {
+        Self {
+            input,
+            buffer: String::new(),
+            exhausted: false,
+            pos: 0,
+            options,
+            last_significant: None,
+            _marker: std::marker::PhantomData,
+        }
     }
 
     /// Get the current position.
@@ -77,14 +433,42 @@ impl<'a> Lexer<'a> {
         self.pos = pos;
     }
 
+    /// Everything pulled from the underlying `Input` so far.
+    ///
+    /// Once the input is exhausted this holds the complete document, so
+    /// `Span`s produced during lexing can be `extract`ed from it.
+    pub fn buffer(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Pull more input until `buffer` holds at least `upto` bytes, or the
+    /// input is exhausted.
+    fn ensure_filled(&mut self, upto: usize) {
+        while !self.exhausted && self.buffer.len() <= upto {
+            if self.input.fill(&mut self.buffer) == 0 {
+                self.exhausted = true;
+            }
+        }
+    }
+
     /// Peek at the current character without consuming it.
-    fn peek(&self) -> Option<char> {
-        self.source[self.pos..].chars().next()
+    fn peek(&mut self) -> Option<char> {
+        self.peek_at(0)
     }
 
     /// Peek at the character at a given offset from current position.
-    fn peek_at(&self, offset: usize) -> Option<char> {
-        self.source[self.pos..].chars().nth(offset)
+    fn peek_at(&mut self, offset: usize) -> Option<char> {
+        loop {
+            if self.buffer.len() >= self.pos {
+                if let Some(ch) = self.buffer[self.pos..].chars().nth(offset) {
+                    return Some(ch);
+                }
+            }
+            if self.exhausted {
+                return None;
+            }
+            self.ensure_filled(self.buffer.len());
+        }
     }
 
     /// Consume and return the current character.
@@ -119,6 +503,26 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    /// Skip a doc comment (from `;;;` to end of line).
+    ///
+    /// Lexically this is just another line comment - skipped without
+    /// producing a token - but the `;;;` marker (modeled on ketos's module
+    /// doc comments) is reserved so that [`crate::node::doc_comment`] can
+    /// find contiguous runs of them by scanning the raw source directly.
+    fn skip_doc_comment(&mut self) {
+        // Consume the `;;;`
+        self.consume();
+        self.consume();
+        self.consume();
+
+        while let Some(ch) = self.peek() {
+            self.consume();
+            if ch == '\n' {
+                break;
+            }
+        }
+    }
+
     /// Skip a block comment (from `#*` to `*#`).
     fn skip_block_comment(&mut self) -> Result<()> {
         let start = self.pos;
@@ -152,7 +556,14 @@ impl<'a> Lexer<'a> {
 
     /// Lex a string literal (from `"` to `"`).
     ///
-    /// Handles escape sequences within the string.
+    /// Finds the closing quote (a backslash always protects the very next
+    /// character from ending the string early, whatever it turns out to
+    /// mean) without validating or decoding escape sequences - that's left
+    /// to [`decode_escapes`] via [`crate::node::text`], called only once the
+    /// string's value is actually requested. Tokenizing a string that is
+    /// never decoded (e.g. a nested value [`crate::node::find_key`] skips
+    /// past) is then never penalized by - or broken by - escapes it never
+    /// needed to understand.
     fn lex_string(&mut self) -> Result<Token> {
         let start = self.pos;
 
@@ -175,7 +586,7 @@ impl<'a> Lexer<'a> {
                 Some('\\') => {
                     // Consume the backslash
                     self.consume();
-                    // Consume the escaped character (validation happens in text())
+                    // Consume whatever follows it - validation is deferred
                     if self.consume().is_none() {
                         return Err(Error::new(
                             ErrorKind::UnclosedString,
@@ -197,7 +608,11 @@ impl<'a> Lexer<'a> {
 
     /// Lex a scalar (unquoted text).
     ///
-    /// Continues until we hit whitespace or a structural character.
+    /// Continues until we hit whitespace, a structural character, or a
+    /// confusable look-alike of one - stopping short there rather than
+    /// swallowing it into the scalar text lets [`Self::next_token_raw`]'s
+    /// confusable check fire on the very next call, instead of only at a
+    /// fresh token boundary.
     fn lex_scalar(&mut self) -> Token {
         let start = self.pos;
 
@@ -215,6 +630,11 @@ impl<'a> Lexer<'a> {
                 break;
             }
 
+            // Stop at a confusable look-alike of a structural character
+            if confusable_ascii(ch).is_some() {
+                break;
+            }
+
             self.consume();
         }
 
@@ -225,7 +645,70 @@ impl<'a> Lexer<'a> {
     }
 
     /// Get the next token.
+    ///
+    /// When [`LexerOptions::suppress_significant_newlines`] is set, a
+    /// `Newline` that would otherwise delimit a value is elided instead -
+    /// silently absorbed like whitespace - whenever it follows a `:`, `,`,
+    /// `{`, or `[`, or immediately precedes a `}` or `]`. This never
+    /// changes the span of the newline itself (it just isn't handed back
+    /// as a token), so spans elsewhere stay accurate.
     pub fn next_token(&mut self) -> Result<Token> {
+        loop {
+            let token = self.next_token_raw()?;
+
+            if token.kind == TokenKind::Newline
+                && self.options.suppress_significant_newlines
+                && self.should_suppress_newline()
+            {
+                continue;
+            }
+
+            self.last_significant = Some(token.kind.clone());
+            return Ok(token);
+        }
+    }
+
+    /// Whether the `Newline` token just consumed by [`Self::next_token_raw`]
+    /// should be suppressed under [`LexerOptions::suppress_significant_newlines`].
+    fn should_suppress_newline(&mut self) -> bool {
+        if matches!(
+            self.last_significant,
+            Some(TokenKind::Colon | TokenKind::Comma | TokenKind::LeftBrace | TokenKind::LeftBracket)
+        ) {
+            return true;
+        }
+
+        // Look past any further whitespace, newlines, and comments without
+        // permanently consuming them, to see whether a closing delimiter
+        // comes next.
+        let saved = self.pos;
+        let closes = loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('\n') => {
+                    self.consume();
+                }
+                Some(';') if self.peek_at(1) == Some(';') && self.peek_at(2) == Some(';') => {
+                    self.skip_doc_comment();
+                }
+                Some('#') if self.peek_at(1) == Some('*') => {
+                    if self.skip_block_comment().is_err() {
+                        break false;
+                    }
+                }
+                Some('#') => {
+                    self.skip_line_comment();
+                }
+                Some('}') | Some(']') => break true,
+                _ => break false,
+            }
+        };
+        self.pos = saved;
+        closes
+    }
+
+    /// Get the next token, without any newline suppression.
+    fn next_token_raw(&mut self) -> Result<Token> {
         loop {
             // Skip non-newline whitespace
             self.skip_whitespace();
@@ -291,6 +774,10 @@ impl<'a> Lexer<'a> {
                 Some('"') => {
                     return self.lex_string();
                 }
+                Some(';') if self.peek_at(1) == Some(';') && self.peek_at(2) == Some(';') => {
+                    self.skip_doc_comment();
+                    continue; // Loop to get next token
+                }
                 Some('#') => {
                     // Check for comments
                     match self.peek_at(1) {
@@ -305,7 +792,17 @@ impl<'a> Lexer<'a> {
                         }
                     }
                 }
-                Some(_) => {
+                Some(ch) => {
+                    if let Some(suggested) = confusable_ascii(ch) {
+                        self.consume();
+                        return Err(Error::new(
+                            ErrorKind::ConfusableChar {
+                                found: ch,
+                                suggested,
+                            },
+                            Span::new(start, ch.len_utf8()),
+                        ));
+                    }
                     return Ok(self.lex_scalar());
                 }
             }
@@ -313,6 +810,80 @@ impl<'a> Lexer<'a> {
     }
 }
 
+/// A buffered view over a [`Lexer`] that supports multi-token lookahead.
+///
+/// The parser used to rewind a raw [`Lexer`] with `set_pos` whenever it
+/// needed to look further ahead than one token - fragile, since positions
+/// have to land on UTF-8 boundaries. `TokenStream` replaces that with a
+/// small `VecDeque` of already-lexed tokens: [`Self::peek`] and
+/// [`Self::peek_nth`] pull from [`Lexer::next_token`] only as far as needed
+/// to fill the buffer, and [`Self::next`] drains it. A lexing error is
+/// cached the moment it's encountered and handed back deterministically to
+/// every subsequent call, rather than re-lexing (and potentially
+/// re-erroring differently) past a position the underlying lexer has
+/// already moved beyond.
+pub struct TokenStream<'a, I: Input = StrInput<'a>> {
+    lexer: Lexer<'a, I>,
+    buffered: VecDeque<Token>,
+    error: Option<Error>,
+}
+
+impl<'a> TokenStream<'a, StrInput<'a>> {
+    /// Create a token stream over `source`, starting at byte offset `start`.
+    pub fn new(source: &'a str, start: usize) -> Self {
+        let mut lexer = Lexer::new(source);
+        lexer.set_pos(start);
+        Self::from_lexer(lexer)
+    }
+}
+
+impl<'a, I: Input> TokenStream<'a, I> {
+    /// Wrap an already-positioned [`Lexer`] in a token stream.
+    pub fn from_lexer(lexer: Lexer<'a, I>) -> Self {
+        Self {
+            lexer,
+            buffered: VecDeque::new(),
+            error: None,
+        }
+    }
+
+    /// Pull tokens from the underlying lexer until the buffer holds at
+    /// least `n + 1` of them, or a lexing error is hit (and cached).
+    fn fill(&mut self, n: usize) {
+        while self.buffered.len() <= n && self.error.is_none() {
+            match self.lexer.next_token() {
+                Ok(token) => self.buffered.push_back(token),
+                Err(err) => self.error = Some(err),
+            }
+        }
+    }
+
+    /// Peek at the next token without consuming it.
+    pub fn peek(&mut self) -> Result<&Token> {
+        self.peek_nth(0)
+    }
+
+    /// Peek `n` tokens ahead (`n = 0` is the same as [`Self::peek`]) without
+    /// consuming anything.
+    pub fn peek_nth(&mut self, n: usize) -> Result<&Token> {
+        self.fill(n);
+        self.buffered
+            .get(n)
+            .ok_or_else(|| self.error.clone().expect("fill only stops short on a cached error"))
+    }
+
+    /// Consume and return the next token.
+    // Not an `Iterator`: it never ends (an exhausted lexer just keeps
+    // yielding `Eof`) and yields a `Result`, not an `Option`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<Token> {
+        self.fill(0);
+        self.buffered
+            .pop_front()
+            .ok_or_else(|| self.error.clone().expect("fill only stops short on a cached error"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -388,4 +959,284 @@ mod tests {
         assert_eq!(lexer.next_token().unwrap().kind, TokenKind::Scalar);
         assert_eq!(lexer.next_token().unwrap().kind, TokenKind::RightBrace);
     }
+
+    #[test]
+    fn lex_confusable_fullwidth_colon() {
+        let mut lexer = Lexer::new("key\u{FF1A} value");
+        lexer.next_token().unwrap(); // "key" scalar
+        let result = lexer.next_token();
+        assert!(matches!(
+            result,
+            Err(Error {
+                kind: ErrorKind::ConfusableChar {
+                    found: '\u{FF1A}',
+                    suggested: ':'
+                },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn lex_confusable_curly_quote() {
+        let mut lexer = Lexer::new("\u{201C}hello\u{201D}");
+        let result = lexer.next_token();
+        assert!(matches!(
+            result,
+            Err(Error {
+                kind: ErrorKind::ConfusableChar {
+                    found: '\u{201C}',
+                    suggested: '"'
+                },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn lex_doc_comment_is_skipped_like_a_line_comment() {
+        let mut lexer = Lexer::new(";;; the answer\n42");
+        let token = lexer.next_token().unwrap();
+        assert_eq!(token.kind, TokenKind::Scalar);
+        assert_eq!(token.span.extract(";;; the answer\n42"), "42");
+    }
+
+    #[test]
+    fn lex_ordinary_unicode_scalar_is_unaffected() {
+        let mut lexer = Lexer::new("世界");
+        let token = lexer.next_token().unwrap();
+        assert_eq!(token.kind, TokenKind::Scalar);
+        assert_eq!(token.span.extract("世界"), "世界");
+    }
+
+    /// An [`Input`] that serves a fixed sequence of chunks, simulating a
+    /// source (a socket, a `Read`er) that delivers text incrementally.
+    struct ChunkedInput<'a> {
+        chunks: std::vec::IntoIter<&'a str>,
+    }
+
+    impl<'a> Input for ChunkedInput<'a> {
+        fn fill(&mut self, buf: &mut String) -> usize {
+            match self.chunks.next() {
+                Some(chunk) => {
+                    buf.push_str(chunk);
+                    chunk.len()
+                }
+                None => 0,
+            }
+        }
+    }
+
+    #[test]
+    fn lexer_over_chunked_input_tokenizes_like_a_str_lexer() {
+        let chunks = vec!["{ a", ": 1,", " b: 2", " }"];
+        let mut lexer = Lexer::from_input(ChunkedInput {
+            chunks: chunks.into_iter(),
+        });
+
+        let mut kinds = Vec::new();
+        loop {
+            let tok = lexer.next_token().unwrap();
+            if tok.kind == TokenKind::Eof {
+                break;
+            }
+            kinds.push(tok.kind);
+        }
+
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::LeftBrace,
+                TokenKind::Scalar,
+                TokenKind::Colon,
+                TokenKind::Scalar,
+                TokenKind::Comma,
+                TokenKind::Scalar,
+                TokenKind::Colon,
+                TokenKind::Scalar,
+                TokenKind::RightBrace,
+            ]
+        );
+        assert_eq!(lexer.buffer(), "{ a: 1, b: 2 }");
+    }
+
+    #[test]
+    fn lex_string_defers_invalid_escape_validation() {
+        // An invalid escape is a content concern, not a structural one: the
+        // lexer only needs to find the closing quote, so this still lexes
+        // to a plain String token. Validation happens later, when (and if)
+        // the value is actually decoded via `crate::node::text`.
+        let source = r#""Invalid \q escape""#;
+        let mut lexer = Lexer::new(source);
+        let token = lexer.next_token().unwrap();
+        assert_eq!(token.kind, TokenKind::String);
+        assert_eq!(token.span.extract(source), source);
+    }
+
+    #[test]
+    fn lexer_over_chunked_input_splits_mid_escape_without_losing_chars() {
+        // The backslash and its escaped character land in different chunks.
+        let chunks = vec![r#""ab\"#, r#"ncd""#];
+        let mut lexer = Lexer::from_input(ChunkedInput {
+            chunks: chunks.into_iter(),
+        });
+        let token = lexer.next_token().unwrap();
+        assert_eq!(token.kind, TokenKind::String);
+        assert_eq!(token.span.extract(lexer.buffer()), r#""ab\ncd""#);
+    }
+
+    #[test]
+    fn newlines_are_significant_by_default() {
+        let mut lexer = Lexer::new("{ a: 1\n b: 2 }");
+        lexer.next_token().unwrap(); // {
+        lexer.next_token().unwrap(); // a
+        lexer.next_token().unwrap(); // :
+        lexer.next_token().unwrap(); // 1
+        assert_eq!(lexer.next_token().unwrap().kind, TokenKind::Newline);
+    }
+
+    #[test]
+    fn newline_after_colon_is_suppressed() {
+        let mut lexer = Lexer::with_options(
+            "a:\n1",
+            LexerOptions {
+                suppress_significant_newlines: true,
+                ..Default::default()
+            },
+        );
+        lexer.next_token().unwrap(); // a
+        lexer.next_token().unwrap(); // :
+        let token = lexer.next_token().unwrap();
+        assert_eq!(token.kind, TokenKind::Scalar);
+        assert_eq!(token.span.extract("a:\n1"), "1");
+    }
+
+    #[test]
+    fn newline_after_comma_and_open_bracket_is_suppressed() {
+        let mut lexer = Lexer::with_options(
+            "[\n1,\n2\n]",
+            LexerOptions {
+                suppress_significant_newlines: true,
+                ..Default::default()
+            },
+        );
+        let mut kinds = Vec::new();
+        loop {
+            let tok = lexer.next_token().unwrap();
+            if tok.kind == TokenKind::Eof {
+                break;
+            }
+            kinds.push(tok.kind);
+        }
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::LeftBracket,
+                TokenKind::Scalar,
+                TokenKind::Comma,
+                TokenKind::Scalar,
+                TokenKind::RightBracket,
+            ]
+        );
+    }
+
+    #[test]
+    fn newline_not_adjacent_to_a_delimiter_still_delimits() {
+        let mut lexer = Lexer::with_options(
+            "1\n2",
+            LexerOptions {
+                suppress_significant_newlines: true,
+                ..Default::default()
+            },
+        );
+        lexer.next_token().unwrap(); // 1
+        assert_eq!(lexer.next_token().unwrap().kind, TokenKind::Newline);
+    }
+
+    #[test]
+    fn suppressed_newline_keeps_following_span_accurate() {
+        // Even though the newline is elided as a token, the characters
+        // around it keep their real byte offsets.
+        let mut lexer = Lexer::with_options(
+            "a:\n1",
+            LexerOptions {
+                suppress_significant_newlines: true,
+                ..Default::default()
+            },
+        );
+        lexer.next_token().unwrap(); // a
+        lexer.next_token().unwrap(); // :
+        let token = lexer.next_token().unwrap();
+        assert_eq!(token.span, Span::new(3, 1));
+    }
+
+    #[test]
+    fn token_stream_peek_does_not_consume() {
+        let mut tokens = TokenStream::new("a: b", 0);
+        assert_eq!(tokens.peek().unwrap().kind, TokenKind::Scalar);
+        assert_eq!(tokens.peek().unwrap().kind, TokenKind::Scalar);
+        assert_eq!(tokens.next().unwrap().kind, TokenKind::Scalar);
+        assert_eq!(tokens.next().unwrap().kind, TokenKind::Colon);
+    }
+
+    #[test]
+    fn token_stream_peek_nth_looks_arbitrarily_far_ahead() {
+        let mut tokens = TokenStream::new("a: b", 0);
+        assert_eq!(tokens.peek_nth(2).unwrap().kind, TokenKind::Scalar); // "b"
+        // Earlier tokens are still there, in order, once drained.
+        assert_eq!(tokens.next().unwrap().kind, TokenKind::Scalar); // "a"
+        assert_eq!(tokens.next().unwrap().kind, TokenKind::Colon);
+        assert_eq!(tokens.next().unwrap().kind, TokenKind::Scalar); // "b"
+    }
+
+    #[test]
+    fn token_stream_starts_at_the_given_offset() {
+        let mut tokens = TokenStream::new("{ a: 1 }", 2);
+        let token = tokens.next().unwrap();
+        assert_eq!(token.kind, TokenKind::Scalar);
+        assert_eq!(token.span.extract("{ a: 1 }"), "a");
+    }
+
+    #[test]
+    fn token_stream_caches_a_lexing_error_deterministically() {
+        let mut tokens = TokenStream::new("\u{FF1A}", 0);
+        let first = tokens.peek().unwrap_err();
+        let second = tokens.next().unwrap_err();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn validate_key_accepts_plain_identifier() {
+        assert_eq!(validate_key("host", 0).unwrap(), None);
+    }
+
+    #[test]
+    fn validate_key_normalizes_decomposed_accents() {
+        // "é" as "e" + combining acute accent (2 chars) should normalize to
+        // the single precomposed character.
+        let decomposed = "cafe\u{0301}";
+        let normalized = validate_key(decomposed, 0).unwrap().unwrap();
+        assert_eq!(normalized, "caf\u{00E9}");
+    }
+
+    #[test]
+    fn validate_key_rejects_non_identifier() {
+        let err = validate_key("42", 0).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidKey(ref k) if k == "42"));
+    }
+
+    #[test]
+    fn validate_key_rejects_empty_string() {
+        let err = validate_key("", 0).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidKey(ref k) if k.is_empty()));
+    }
+
+    #[test]
+    fn validate_key_error_span_covers_the_raw_key() {
+        let source = "  bad-key: 1";
+        let key = "bad-key";
+        let start = source.find(key).unwrap();
+        let err = validate_key(key, start).unwrap_err();
+        assert_eq!(err.span, Span::new(start, key.len()));
+    }
 }
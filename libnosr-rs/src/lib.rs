@@ -7,6 +7,11 @@
 //! scalar values at the leaves. The format is designed to be simple and flexible,
 //! allowing you to parse values on-demand rather than converting everything upfront.
 //!
+//! [`lexer::Lexer`] can tokenize from any [`lexer::Input`], including one that
+//! pulls text incrementally from a `Read`er, but that doesn't extend to the
+//! rest of the crate - [`Node`] holds a materialized `&str`, so [`document`]
+//! and friends still require the whole source up front.
+//!
 //! ## Example
 //!
 //! ```rust
@@ -40,13 +45,16 @@ pub mod parser;
 pub mod span;
 
 // Re-export the main API types and functions
-pub use error::{ParseError, Result};
+pub use error::{Error, Result};
 pub use node::Node;
-pub use span::Span;
+pub use span::{LineCol, SourceLocation, SourceMap, Span};
 
 // Re-export the main API functions
-pub use node::{double, table, text, uint64, vector};
-pub use parser::document;
+pub use node::{
+    doc_comment, double, find_key, int64, rational, table, table_get_suggested, table_recover,
+    text, uint64, vector, vector_recover,
+};
+pub use parser::{document, document_all};
 
 #[cfg(test)]
 mod tests {
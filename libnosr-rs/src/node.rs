@@ -41,6 +41,61 @@ impl<'a> Node<'a> {
     }
 }
 
+/// Return the doc comment immediately preceding a node's span, if any.
+///
+/// Doc comments are lines beginning with `;;;` (modeled on ketos's module
+/// doc comments), and are skipped by the lexer like any other comment, so
+/// they never interfere with `table`/`vector` parsing. This walks the raw
+/// source backward from `node`'s own line, collecting the contiguous run of
+/// `;;;` lines directly above it - stopping at the first blank or
+/// non-doc-comment line - and strips the marker (and one following space,
+/// if present) from each.
+///
+/// # Example
+///
+/// ```rust
+/// use libnosr_rs::{document, table};
+/// use libnosr_rs::node::doc_comment;
+///
+/// let source = "{\n;;; The server's hostname.\nhost: localhost\n}";
+/// let root = document(source).unwrap();
+/// let tbl = table(&root).unwrap();
+/// let host = tbl.get("host").unwrap();
+/// assert_eq!(doc_comment(host).unwrap(), "The server's hostname.");
+/// ```
+pub fn doc_comment<'a>(node: &Node<'a>) -> Option<Cow<'a, str>> {
+    let source = node.source;
+    let line_start = source[..node.span.start]
+        .rfind('\n')
+        .map(|i| i + 1)
+        .unwrap_or(0);
+
+    let mut lines = Vec::new();
+    let mut pos = line_start;
+    while pos > 0 {
+        let prev_line_start = source[..pos - 1].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let candidate = source[prev_line_start..pos - 1].trim();
+        match candidate.strip_prefix(";;;") {
+            Some(rest) => {
+                lines.push(rest.strip_prefix(' ').unwrap_or(rest));
+                pos = prev_line_start;
+            }
+            None => break,
+        }
+    }
+
+    if lines.is_empty() {
+        return None;
+    }
+
+    lines.reverse();
+    if lines.len() == 1 {
+        Some(Cow::Borrowed(lines[0]))
+    } else {
+        Some(Cow::Owned(lines.join("\n")))
+    }
+}
+
 /// Parse a node as a table.
 ///
 /// # Example
@@ -55,7 +110,39 @@ impl<'a> Node<'a> {
 /// assert_eq!(text(name).unwrap(), "Alice");
 /// ```
 pub fn table<'a>(node: &Node<'a>) -> Result<HashMap<String, Node<'a>>> {
-    use crate::lexer::{Lexer, TokenKind};
+    table_with_options(node, crate::lexer::LexerOptions::default())
+}
+
+/// Parse a node as a table, applying `options` to the underlying lexer.
+///
+/// With [`LexerOptions::validate_keys`] set, every bare scalar key is
+/// NFC-normalized and validated as a legal identifier via
+/// [`crate::lexer::validate_key`] before being used to key the returned
+/// map, so visually identical keys with different Unicode compositions
+/// collide rather than silently coexisting. Quoted string keys are
+/// unaffected. A key that fails validation reports
+/// [`ErrorKind::InvalidKey`] with a span over the original source text.
+///
+/// # Example
+///
+/// ```rust
+/// use libnosr_rs::document;
+/// use libnosr_rs::lexer::LexerOptions;
+/// use libnosr_rs::node::table_with_options;
+///
+/// let source = "{ bad-key: 1 }";
+/// let root = document(source).unwrap();
+/// let options = LexerOptions {
+///     validate_keys: true,
+///     ..Default::default()
+/// };
+/// assert!(table_with_options(&root, options).is_err());
+/// ```
+pub fn table_with_options<'a>(
+    node: &Node<'a>,
+    options: crate::lexer::LexerOptions,
+) -> Result<HashMap<String, Node<'a>>> {
+    use crate::lexer::{TokenKind, TokenStream};
 
     let content = node.raw().trim();
 
@@ -65,14 +152,13 @@ pub fn table<'a>(node: &Node<'a>) -> Result<HashMap<String, Node<'a>>> {
     }
 
     // Parse the table to collect all key-value pairs
-    let mut lexer = Lexer::new(node.source);
-    let mut result = HashMap::new();
-
-    // Seek the lexer to our starting position
+    let mut lexer = crate::lexer::Lexer::with_options(node.source, options);
     lexer.set_pos(node.span.start);
+    let mut tokens = TokenStream::from_lexer(lexer);
+    let mut result = HashMap::new();
 
     // Consume the opening brace
-    let token = lexer.next_token()?;
+    let token = tokens.next()?;
     if token.kind != TokenKind::LeftBrace {
         return Err(Error::new(ErrorKind::NotATable, node.span));
     }
@@ -80,7 +166,7 @@ pub fn table<'a>(node: &Node<'a>) -> Result<HashMap<String, Node<'a>>> {
     // Parse key-value pairs
     loop {
         // Skip delimiters, but detect consecutive commas without intervening newlines
-        let mut tok = lexer.next_token()?;
+        let mut tok = tokens.next()?;
         let mut saw_comma = false;
         while matches!(tok.kind, TokenKind::Newline | TokenKind::Comma) {
             if tok.kind == TokenKind::Comma {
@@ -93,7 +179,7 @@ pub fn table<'a>(node: &Node<'a>) -> Result<HashMap<String, Node<'a>>> {
                 // Newline resets the comma tracking
                 saw_comma = false;
             }
-            tok = lexer.next_token()?;
+            tok = tokens.next()?;
         }
 
         // Check for end of table
@@ -108,30 +194,36 @@ pub fn table<'a>(node: &Node<'a>) -> Result<HashMap<String, Node<'a>>> {
             let key_node = Node::new(node.source, key_span);
             text(&key_node)?.into_owned()
         } else if tok.kind == TokenKind::Scalar {
-            key_span.extract(node.source).to_string()
+            let raw_key = key_span.extract(node.source);
+            if options.validate_keys {
+                crate::lexer::validate_key(raw_key, key_span.start)?
+                    .unwrap_or_else(|| raw_key.to_string())
+            } else {
+                raw_key.to_string()
+            }
         } else {
             return Err(Error::new(ErrorKind::ExpectedChar(':'), tok.span));
         };
 
         // Expect a colon
-        tok = lexer.next_token()?;
+        tok = tokens.next()?;
         if tok.kind != TokenKind::Colon {
             return Err(Error::new(ErrorKind::ExpectedChar(':'), tok.span));
         }
 
         // Get the value
-        tok = lexer.next_token()?;
+        tok = tokens.next()?;
         let value_start = tok.span;
 
         // Determine value extent (might be a nested structure)
         let value_end = match tok.kind {
             TokenKind::LeftBrace => {
                 // Parse nested table
-                parse_balanced(node.source, &mut lexer, TokenKind::RightBrace)?
+                parse_balanced(node.source, &mut tokens, TokenKind::RightBrace)?
             }
             TokenKind::LeftBracket => {
                 // Parse nested vector
-                parse_balanced(node.source, &mut lexer, TokenKind::RightBracket)?
+                parse_balanced(node.source, &mut tokens, TokenKind::RightBracket)?
             }
             TokenKind::String | TokenKind::Scalar => {
                 // Simple value
@@ -164,7 +256,7 @@ pub fn table<'a>(node: &Node<'a>) -> Result<HashMap<String, Node<'a>>> {
 /// Returns the span of the closing delimiter.
 fn parse_balanced(
     _source: &str,
-    lexer: &mut crate::lexer::Lexer,
+    tokens: &mut crate::lexer::TokenStream,
     closing: crate::lexer::TokenKind,
 ) -> Result<Span> {
     use crate::lexer::TokenKind;
@@ -173,7 +265,7 @@ fn parse_balanced(
     let mut last_span = Span::new(0, 0);
 
     while depth > 0 {
-        let tok = lexer.next_token()?;
+        let tok = tokens.next()?;
         last_span = tok.span;
 
         match tok.kind {
@@ -196,6 +288,366 @@ fn parse_balanced(
     Ok(last_span)
 }
 
+/// Maximum number of errors the `*_recover` functions will collect before
+/// giving up on reporting more of them, to avoid a single bad token
+/// cascading into hundreds of spurious reports.
+const MAX_RECOVER_ERRORS: usize = 100;
+
+/// Push an error onto the list unless the cap has already been reached.
+fn push_capped(errors: &mut Vec<Error>, err: Error) {
+    if errors.len() < MAX_RECOVER_ERRORS {
+        errors.push(err);
+    }
+}
+
+/// Skip tokens until the next top-level delimiter directly inside the
+/// structure being recovered (a newline or comma at depth 1), or until its
+/// own closing brace/bracket is consumed.
+///
+/// Returns `true` if the structure's closing delimiter was reached (the
+/// caller should stop looking for more pairs/elements), `false` if a
+/// newline or comma was found to resume from.
+fn recover_to_next_entry(tokens: &mut crate::lexer::TokenStream, errors: &mut Vec<Error>) -> bool {
+    use crate::lexer::TokenKind;
+
+    let mut depth: i64 = 1;
+    loop {
+        match tokens.next() {
+            Ok(tok) => match tok.kind {
+                TokenKind::Eof => return true,
+                TokenKind::LeftBrace | TokenKind::LeftBracket => depth += 1,
+                TokenKind::RightBrace | TokenKind::RightBracket => {
+                    depth -= 1;
+                    if depth <= 0 {
+                        return true;
+                    }
+                }
+                TokenKind::Newline | TokenKind::Comma if depth == 1 => return false,
+                _ => {}
+            },
+            Err(err) => push_capped(errors, err),
+        }
+    }
+}
+
+/// Parse a node as a table, collecting every malformed entry as an `Error`
+/// instead of bailing out on the first one.
+///
+/// Mirrors the error-recovery strategy in rustc's parser diagnostics: on an
+/// unexpected or missing token, the error is recorded and the scan
+/// resynchronizes by skipping tokens until the next top-level delimiter
+/// (comma/newline) or the table's matching closing brace, tracking nesting
+/// depth so nested structures don't prematurely terminate recovery.
+/// Successfully parsed pairs are still returned; failed ones are omitted.
+///
+/// This lets tooling surface every problem in a config file in one pass.
+///
+/// # Example
+///
+/// ```rust
+/// use libnosr_rs::document;
+/// use libnosr_rs::node::table_recover;
+///
+/// let source = "{ a: 1, b: :, c: 3 }";
+/// let root = document(source).unwrap();
+/// let (entries, errors) = table_recover(&root);
+/// assert_eq!(entries.len(), 2);
+/// assert_eq!(errors.len(), 1);
+/// ```
+pub fn table_recover<'a>(node: &Node<'a>) -> (HashMap<String, Node<'a>>, Vec<Error>) {
+    use crate::lexer::{TokenKind, TokenStream};
+
+    let mut result = HashMap::new();
+    let mut errors = Vec::new();
+
+    let content = node.raw().trim();
+    if !content.starts_with('{') {
+        errors.push(Error::new(ErrorKind::NotATable, node.span));
+        return (result, errors);
+    }
+
+    let mut tokens = TokenStream::new(node.source, node.span.start);
+
+    match tokens.next() {
+        Ok(tok) if tok.kind == TokenKind::LeftBrace => {}
+        Ok(tok) => {
+            errors.push(Error::new(ErrorKind::NotATable, tok.span));
+            return (result, errors);
+        }
+        Err(err) => {
+            errors.push(err);
+            return (result, errors);
+        }
+    }
+
+    'entries: loop {
+        // Skip delimiters, but detect consecutive commas without intervening newlines
+        let mut tok = match tokens.next() {
+            Ok(tok) => tok,
+            Err(err) => {
+                push_capped(&mut errors, err);
+                if recover_to_next_entry(&mut tokens, &mut errors) {
+                    break;
+                }
+                continue;
+            }
+        };
+        let mut saw_comma = false;
+        while matches!(tok.kind, TokenKind::Newline | TokenKind::Comma) {
+            if tok.kind == TokenKind::Comma {
+                if saw_comma {
+                    push_capped(
+                        &mut errors,
+                        Error::new(ErrorKind::ConsecutiveDelimiters, tok.span),
+                    );
+                    if recover_to_next_entry(&mut tokens, &mut errors) {
+                        break 'entries;
+                    }
+                }
+                saw_comma = true;
+            } else {
+                saw_comma = false;
+            }
+            tok = match tokens.next() {
+                Ok(tok) => tok,
+                Err(err) => {
+                    push_capped(&mut errors, err);
+                    if recover_to_next_entry(&mut tokens, &mut errors) {
+                        break 'entries;
+                    }
+                    continue 'entries;
+                }
+            };
+        }
+
+        if tok.kind == TokenKind::RightBrace {
+            break;
+        }
+
+        // Get the key (should be a string or scalar)
+        let key_span = tok.span;
+        let key_text = if tok.kind == TokenKind::String {
+            let key_node = Node::new(node.source, key_span);
+            match text(&key_node) {
+                Ok(t) => t.into_owned(),
+                Err(err) => {
+                    push_capped(&mut errors, err);
+                    if recover_to_next_entry(&mut tokens, &mut errors) {
+                        break;
+                    }
+                    continue;
+                }
+            }
+        } else if tok.kind == TokenKind::Scalar {
+            key_span.extract(node.source).to_string()
+        } else {
+            push_capped(
+                &mut errors,
+                Error::new(ErrorKind::ExpectedChar(':'), tok.span),
+            );
+            if recover_to_next_entry(&mut tokens, &mut errors) {
+                break;
+            }
+            continue;
+        };
+
+        // Expect a colon
+        tok = match tokens.next() {
+            Ok(tok) => tok,
+            Err(err) => {
+                push_capped(&mut errors, err);
+                if recover_to_next_entry(&mut tokens, &mut errors) {
+                    break;
+                }
+                continue;
+            }
+        };
+        if tok.kind != TokenKind::Colon {
+            push_capped(
+                &mut errors,
+                Error::new(ErrorKind::ExpectedChar(':'), tok.span),
+            );
+            if recover_to_next_entry(&mut tokens, &mut errors) {
+                break;
+            }
+            continue;
+        }
+
+        // Get the value
+        tok = match tokens.next() {
+            Ok(tok) => tok,
+            Err(err) => {
+                push_capped(&mut errors, err);
+                if recover_to_next_entry(&mut tokens, &mut errors) {
+                    break;
+                }
+                continue;
+            }
+        };
+        let value_start = tok.span;
+
+        let value_end = match tok.kind {
+            TokenKind::LeftBrace => {
+                match parse_balanced(node.source, &mut tokens, TokenKind::RightBrace) {
+                    Ok(span) => span,
+                    Err(err) => {
+                        push_capped(&mut errors, err);
+                        if recover_to_next_entry(&mut tokens, &mut errors) {
+                            break;
+                        }
+                        continue;
+                    }
+                }
+            }
+            TokenKind::LeftBracket => {
+                match parse_balanced(node.source, &mut tokens, TokenKind::RightBracket) {
+                    Ok(span) => span,
+                    Err(err) => {
+                        push_capped(&mut errors, err);
+                        if recover_to_next_entry(&mut tokens, &mut errors) {
+                            break;
+                        }
+                        continue;
+                    }
+                }
+            }
+            TokenKind::String | TokenKind::Scalar => tok.span,
+            _ => {
+                push_capped(
+                    &mut errors,
+                    Error::new(
+                        ErrorKind::UnexpectedChar(
+                            value_start
+                                .extract(node.source)
+                                .chars()
+                                .next()
+                                .unwrap_or(' '),
+                        ),
+                        value_start,
+                    ),
+                );
+                if recover_to_next_entry(&mut tokens, &mut errors) {
+                    break;
+                }
+                continue;
+            }
+        };
+
+        let value_span = Span::new(value_start.start, value_end.end() - value_start.start);
+        result.insert(key_text, Node::new(node.source, value_span));
+    }
+
+    (result, errors)
+}
+
+/// Look up a single key in a table without materializing the rest of it.
+///
+/// Unlike [`table`], which builds the full `HashMap` up front, `find_key`
+/// starts a token stream at `node`'s span and walks key/value pairs one at a
+/// time, skipping past any value that isn't the one we're after via
+/// [`parse_balanced`]'s depth counting - without decoding it or allocating
+/// a `Node` for it. Returns as soon as the target key is found, so for
+/// documents where only a handful of fields out of a large or deeply
+/// nested table are needed, this turns a whole-subtree parse into a
+/// single linear scan.
+///
+/// # Example
+///
+/// ```rust
+/// use libnosr_rs::{document, text};
+/// use libnosr_rs::node::find_key;
+///
+/// let source = "{ name: Alice, age: 30 }";
+/// let root = document(source).unwrap();
+/// let name = find_key(&root, "name").unwrap().unwrap();
+/// assert_eq!(text(&name).unwrap(), "Alice");
+/// assert!(find_key(&root, "missing").unwrap().is_none());
+/// ```
+pub fn find_key<'a>(node: &Node<'a>, key: &str) -> Result<Option<Node<'a>>> {
+    use crate::lexer::{TokenKind, TokenStream};
+
+    let content = node.raw().trim();
+
+    if !content.starts_with('{') {
+        return Err(Error::new(ErrorKind::NotATable, node.span));
+    }
+
+    let mut tokens = TokenStream::new(node.source, node.span.start);
+
+    let token = tokens.next()?;
+    if token.kind != TokenKind::LeftBrace {
+        return Err(Error::new(ErrorKind::NotATable, node.span));
+    }
+
+    loop {
+        // Skip delimiters, but detect consecutive commas without intervening newlines
+        let mut tok = tokens.next()?;
+        let mut saw_comma = false;
+        while matches!(tok.kind, TokenKind::Newline | TokenKind::Comma) {
+            if tok.kind == TokenKind::Comma {
+                if saw_comma {
+                    return Err(Error::new(ErrorKind::ConsecutiveDelimiters, tok.span));
+                }
+                saw_comma = true;
+            } else {
+                saw_comma = false;
+            }
+            tok = tokens.next()?;
+        }
+
+        if tok.kind == TokenKind::RightBrace {
+            return Ok(None);
+        }
+
+        // Get the key (should be a string or scalar)
+        let key_span = tok.span;
+        let key_text = if tok.kind == TokenKind::String {
+            let key_node = Node::new(node.source, key_span);
+            text(&key_node)?
+        } else if tok.kind == TokenKind::Scalar {
+            Cow::Borrowed(key_span.extract(node.source))
+        } else {
+            return Err(Error::new(ErrorKind::ExpectedChar(':'), tok.span));
+        };
+
+        // Expect a colon
+        tok = tokens.next()?;
+        if tok.kind != TokenKind::Colon {
+            return Err(Error::new(ErrorKind::ExpectedChar(':'), tok.span));
+        }
+
+        // Seek past the value, whether or not it's the one we want - only
+        // decoding the span, never the nested structure itself
+        tok = tokens.next()?;
+        let value_start = tok.span;
+
+        let value_end = match tok.kind {
+            TokenKind::LeftBrace => parse_balanced(node.source, &mut tokens, TokenKind::RightBrace)?,
+            TokenKind::LeftBracket => {
+                parse_balanced(node.source, &mut tokens, TokenKind::RightBracket)?
+            }
+            TokenKind::String | TokenKind::Scalar => tok.span,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::UnexpectedChar(
+                        value_start
+                            .extract(node.source)
+                            .chars()
+                            .next()
+                            .unwrap_or(' '),
+                    ),
+                    value_start,
+                ));
+            }
+        };
+
+        if key_text == key {
+            let value_span = Span::new(value_start.start, value_end.end() - value_start.start);
+            return Ok(Some(Node::new(node.source, value_span)));
+        }
+    }
+}
+
 /// Parse a node as a vector and return all elements.
 ///
 /// # Example
@@ -210,7 +662,37 @@ fn parse_balanced(
 /// assert_eq!(text(&v[1]).unwrap(), "world");
 /// ```
 pub fn vector<'a>(node: &Node<'a>) -> Result<Vec<Node<'a>>> {
-    use crate::lexer::{Lexer, TokenKind};
+    vector_with_options(node, crate::lexer::LexerOptions::default())
+}
+
+/// Parse a node as a vector, applying `options` to the underlying lexer.
+///
+/// With [`LexerOptions::suppress_significant_newlines`] set, an element can
+/// wrap across multiple lines (e.g. after a `,` or `[`) without the newline
+/// being mistaken for a delimiter.
+///
+/// # Example
+///
+/// ```rust
+/// use libnosr_rs::document;
+/// use libnosr_rs::lexer::LexerOptions;
+/// use libnosr_rs::node::{vector_with_options, text};
+///
+/// let source = "[\n  one,\n  two\n]";
+/// let root = document(source).unwrap();
+/// let options = LexerOptions {
+///     suppress_significant_newlines: true,
+///     ..Default::default()
+/// };
+/// let v = vector_with_options(&root, options).unwrap();
+/// assert_eq!(text(&v[0]).unwrap(), "one");
+/// assert_eq!(text(&v[1]).unwrap(), "two");
+/// ```
+pub fn vector_with_options<'a>(
+    node: &Node<'a>,
+    options: crate::lexer::LexerOptions,
+) -> Result<Vec<Node<'a>>> {
+    use crate::lexer::{TokenKind, TokenStream};
 
     let content = node.raw().trim();
 
@@ -220,14 +702,13 @@ pub fn vector<'a>(node: &Node<'a>) -> Result<Vec<Node<'a>>> {
     }
 
     // Parse the vector to collect all elements
-    let mut lexer = Lexer::new(node.source);
-    let mut result = Vec::new();
-
-    // Seek the lexer to our starting position
+    let mut lexer = crate::lexer::Lexer::with_options(node.source, options);
     lexer.set_pos(node.span.start);
+    let mut tokens = TokenStream::from_lexer(lexer);
+    let mut result = Vec::new();
 
     // Consume the opening bracket
-    let token = lexer.next_token()?;
+    let token = tokens.next()?;
     if token.kind != TokenKind::LeftBracket {
         return Err(Error::new(ErrorKind::NotAVector, node.span));
     }
@@ -235,7 +716,7 @@ pub fn vector<'a>(node: &Node<'a>) -> Result<Vec<Node<'a>>> {
     // Parse elements
     loop {
         // Skip delimiters, but detect consecutive commas without intervening newlines
-        let mut tok = lexer.next_token()?;
+        let mut tok = tokens.next()?;
         let mut saw_comma = false;
         while matches!(tok.kind, TokenKind::Newline | TokenKind::Comma) {
             if tok.kind == TokenKind::Comma {
@@ -248,7 +729,7 @@ pub fn vector<'a>(node: &Node<'a>) -> Result<Vec<Node<'a>>> {
                 // Newline resets the comma tracking
                 saw_comma = false;
             }
-            tok = lexer.next_token()?;
+            tok = tokens.next()?;
         }
 
         // Check for end of vector
@@ -263,11 +744,11 @@ pub fn vector<'a>(node: &Node<'a>) -> Result<Vec<Node<'a>>> {
         let elem_end = match tok.kind {
             TokenKind::LeftBrace => {
                 // Parse nested table
-                parse_balanced(node.source, &mut lexer, TokenKind::RightBrace)?
+                parse_balanced(node.source, &mut tokens, TokenKind::RightBrace)?
             }
             TokenKind::LeftBracket => {
                 // Parse nested vector
-                parse_balanced(node.source, &mut lexer, TokenKind::RightBracket)?
+                parse_balanced(node.source, &mut tokens, TokenKind::RightBracket)?
             }
             TokenKind::String | TokenKind::Scalar => {
                 // Simple value
@@ -295,74 +776,196 @@ pub fn vector<'a>(node: &Node<'a>) -> Result<Vec<Node<'a>>> {
     Ok(result)
 }
 
-/// Parse a node as a text string.
+/// Parse a node as a vector, collecting every malformed element as an
+/// `Error` instead of bailing out on the first one.
 ///
-/// Handles both quoted strings (with escape sequences) and raw scalars.
+/// Uses the same resynchronization strategy as [`table_recover`]: on an
+/// unexpected token the error is recorded and the scan skips ahead to the
+/// next top-level comma/newline or the vector's matching closing bracket,
+/// tracking nesting depth so nested structures aren't cut short.
 ///
 /// # Example
 ///
 /// ```rust
-/// use libnosr_rs::{document, text};
-///
-/// let node = document("\"hello world\"").unwrap();
-/// assert_eq!(text(&node).unwrap(), "hello world");
+/// use libnosr_rs::document;
+/// use libnosr_rs::node::vector_recover;
 ///
-/// let node2 = document("hello").unwrap();
-/// assert_eq!(text(&node2).unwrap(), "hello");
+/// let source = "[1, :, 3]";
+/// let root = document(source).unwrap();
+/// let (elements, errors) = vector_recover(&root);
+/// assert_eq!(elements.len(), 2);
+/// assert_eq!(errors.len(), 1);
 /// ```
-pub fn text<'a>(node: &Node<'a>) -> Result<Cow<'a, str>> {
-    let content = node.raw().trim();
+pub fn vector_recover<'a>(node: &Node<'a>) -> (Vec<Node<'a>>, Vec<Error>) {
+    use crate::lexer::{TokenKind, TokenStream};
 
-    if content.is_empty() {
-        return Err(Error::new(ErrorKind::NotAScalar, node.span));
+    let mut result = Vec::new();
+    let mut errors = Vec::new();
+
+    let content = node.raw().trim();
+    if !content.starts_with('[') {
+        errors.push(Error::new(ErrorKind::NotAVector, node.span));
+        return (result, errors);
     }
 
-    // Check if it's a quoted string
-    if content.starts_with('"') {
-        if !content.ends_with('"') || content.len() < 2 {
-            return Err(Error::new(ErrorKind::UnclosedString, node.span));
+    let mut tokens = TokenStream::new(node.source, node.span.start);
+
+    match tokens.next() {
+        Ok(tok) if tok.kind == TokenKind::LeftBracket => {}
+        Ok(tok) => {
+            errors.push(Error::new(ErrorKind::NotAVector, tok.span));
+            return (result, errors);
         }
+        Err(err) => {
+            errors.push(err);
+            return (result, errors);
+        }
+    }
 
-        // Extract the content between quotes
-        let inner = &content[1..content.len() - 1];
-
-        // Process the string, only allocating if we find escape sequences
-        let mut result = None;
-        let mut chars = inner.chars();
-        let mut pos = 0;
-
-        while let Some(ch) = chars.next() {
-            if ch == '\\' {
-                // Found an escape - initialize result if needed
-                let s = result.get_or_insert_with(|| String::from(&inner[..pos]));
-
-                match chars.next() {
-                    Some('\\') => s.push('\\'),
-                    Some('n') => s.push('\n'),
-                    Some('t') => s.push('\t'),
-                    Some('r') => s.push('\r'),
-                    Some(':') => s.push(':'),
-                    Some('"') => s.push('"'),
-                    Some('[') => s.push('['),
-                    Some(']') => s.push(']'),
-                    Some('{') => s.push('{'),
-                    Some('}') => s.push('}'),
-                    Some(other) => {
-                        return Err(Error::new(ErrorKind::InvalidEscape(other), node.span));
+    'elements: loop {
+        // Skip delimiters, but detect consecutive commas without intervening newlines
+        let mut tok = match tokens.next() {
+            Ok(tok) => tok,
+            Err(err) => {
+                push_capped(&mut errors, err);
+                if recover_to_next_entry(&mut tokens, &mut errors) {
+                    break;
+                }
+                continue;
+            }
+        };
+        let mut saw_comma = false;
+        while matches!(tok.kind, TokenKind::Newline | TokenKind::Comma) {
+            if tok.kind == TokenKind::Comma {
+                if saw_comma {
+                    push_capped(
+                        &mut errors,
+                        Error::new(ErrorKind::ConsecutiveDelimiters, tok.span),
+                    );
+                    if recover_to_next_entry(&mut tokens, &mut errors) {
+                        break 'elements;
                     }
-                    None => return Err(Error::new(ErrorKind::UnexpectedEof, node.span)),
                 }
-                pos += 2; // backslash + escaped char
+                saw_comma = true;
             } else {
-                if let Some(ref mut s) = result {
-                    s.push(ch);
+                saw_comma = false;
+            }
+            tok = match tokens.next() {
+                Ok(tok) => tok,
+                Err(err) => {
+                    push_capped(&mut errors, err);
+                    if recover_to_next_entry(&mut tokens, &mut errors) {
+                        break 'elements;
+                    }
+                    continue 'elements;
                 }
-                pos += ch.len_utf8();
+            };
+        }
+
+        if tok.kind == TokenKind::RightBracket {
+            break;
+        }
+
+        let elem_start = tok.span;
+
+        let elem_end = match tok.kind {
+            TokenKind::LeftBrace => {
+                match parse_balanced(node.source, &mut tokens, TokenKind::RightBrace) {
+                    Ok(span) => span,
+                    Err(err) => {
+                        push_capped(&mut errors, err);
+                        if recover_to_next_entry(&mut tokens, &mut errors) {
+                            break;
+                        }
+                        continue;
+                    }
+                }
+            }
+            TokenKind::LeftBracket => {
+                match parse_balanced(node.source, &mut tokens, TokenKind::RightBracket) {
+                    Ok(span) => span,
+                    Err(err) => {
+                        push_capped(&mut errors, err);
+                        if recover_to_next_entry(&mut tokens, &mut errors) {
+                            break;
+                        }
+                        continue;
+                    }
+                }
+            }
+            TokenKind::String | TokenKind::Scalar => tok.span,
+            _ => {
+                push_capped(
+                    &mut errors,
+                    Error::new(
+                        ErrorKind::UnexpectedChar(
+                            elem_start
+                                .extract(node.source)
+                                .chars()
+                                .next()
+                                .unwrap_or(' '),
+                        ),
+                        elem_start,
+                    ),
+                );
+                if recover_to_next_entry(&mut tokens, &mut errors) {
+                    break;
+                }
+                continue;
             }
+        };
+
+        let elem_span = Span::new(elem_start.start, elem_end.end() - elem_start.start);
+        result.push(Node::new(node.source, elem_span));
+    }
+
+    (result, errors)
+}
+
+/// Parse a node as a text string.
+///
+/// Handles both quoted strings (with escape sequences) and raw scalars.
+///
+/// # Example
+///
+/// ```rust
+/// use libnosr_rs::{document, text};
+///
+/// let node = document("\"hello world\"").unwrap();
+/// assert_eq!(text(&node).unwrap(), "hello world");
+///
+/// let node2 = document("hello").unwrap();
+/// assert_eq!(text(&node2).unwrap(), "hello");
+/// ```
+pub fn text<'a>(node: &Node<'a>) -> Result<Cow<'a, str>> {
+    let content = node.raw().trim();
+
+    if content.is_empty() {
+        return Err(Error::new(ErrorKind::NotAScalar, node.span));
+    }
+
+    // Check if it's a quoted string
+    if content.starts_with('"') {
+        if !content.ends_with('"') || content.len() < 2 {
+            return Err(Error::new(ErrorKind::UnclosedString, node.span));
         }
 
-        // Return owned if we found escapes, borrowed otherwise
-        Ok(result.map(Cow::Owned).unwrap_or(Cow::Borrowed(inner)))
+        // Extract the content between quotes
+        let inner = &content[1..content.len() - 1];
+
+        // Byte offset of `inner` within the original source, so escape
+        // errors can carry a span over just the offending backslash and
+        // escape character rather than the whole string literal.
+        let leading_trim = node.raw().len() - node.raw().trim_start().len();
+        let inner_start = node.span.start + leading_trim + 1;
+
+        // The lexer only finds the closing quote; it does not validate or
+        // decode escapes. That happens here, via `decode_escapes`, which
+        // returns the decoded value - or nothing, in which case `inner` is
+        // borrowed directly since it had no escapes to decode.
+        Ok(crate::lexer::decode_escapes(inner, inner_start)?
+            .map(Cow::Owned)
+            .unwrap_or(Cow::Borrowed(inner)))
     } else {
         // Unquoted scalar - return as-is
         Ok(Cow::Borrowed(content))
@@ -411,6 +1014,164 @@ pub fn double(node: &Node) -> Result<f64> {
     })
 }
 
+/// Parse a node as a signed 64-bit integer.
+///
+/// # Example
+///
+/// ```rust
+/// use libnosr_rs::{document, int64};
+///
+/// let node = document("-42").unwrap();
+/// assert_eq!(int64(&node).unwrap(), -42);
+/// ```
+pub fn int64(node: &Node) -> Result<i64> {
+    let content = node.raw().trim();
+
+    content.parse::<i64>().map_err(|e| {
+        Error::new(
+            ErrorKind::ParseError(format!("failed to parse as i64: {}", e)),
+            node.span,
+        )
+    })
+}
+
+/// Parse a node as an exact rational number of the form `[-]N/D`.
+///
+/// Unlike `double`, this never loses precision: the numerator and
+/// denominator are reduced to lowest terms via the Euclidean algorithm,
+/// with the sign normalized onto the numerator. Useful for money or
+/// measurement fields that need to round-trip exactly.
+///
+/// # Example
+///
+/// ```rust
+/// use libnosr_rs::{document, rational};
+///
+/// let node = document("-6/8").unwrap();
+/// assert_eq!(rational(&node).unwrap(), (-3, 4));
+/// ```
+pub fn rational(node: &Node) -> Result<(i128, i128)> {
+    let content = node.raw().trim();
+
+    let (negative, rest) = match content.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, content),
+    };
+
+    let (num_str, den_str) = rest.split_once('/').ok_or_else(|| {
+        Error::new(
+            ErrorKind::ParseError(format!(
+                "expected a rational of the form [-]N/D, got '{}'",
+                content
+            )),
+            node.span,
+        )
+    })?;
+
+    let numerator: u128 = num_str.parse().map_err(|e| {
+        Error::new(
+            ErrorKind::ParseError(format!("failed to parse numerator: {}", e)),
+            node.span,
+        )
+    })?;
+
+    let denominator: u128 = den_str.parse().map_err(|e| {
+        Error::new(
+            ErrorKind::ParseError(format!("failed to parse denominator: {}", e)),
+            node.span,
+        )
+    })?;
+
+    if denominator == 0 {
+        return Err(Error::new(ErrorKind::ZeroDenominator, node.span));
+    }
+
+    let divisor = gcd(numerator, denominator).max(1);
+    let num = (numerator / divisor) as i128;
+    let den = (denominator / divisor) as i128;
+
+    Ok((if negative { -num } else { num }, den))
+}
+
+/// Compute the greatest common divisor of two non-negative integers using
+/// the Euclidean algorithm.
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Compute the Levenshtein edit distance between two strings.
+///
+/// Uses a rolling two-row dynamic-programming table instead of a full
+/// matrix, with cost 1 for each insertion, deletion, or substitution.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+
+    for (i, &a_ch) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, &b_ch) in b.iter().enumerate() {
+            let cost = if a_ch == b_ch { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1) // deletion
+                .min(curr[j] + 1) // insertion
+                .min(prev[j] + cost); // substitution
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// Find the closest of `candidates` to `key` by Levenshtein distance.
+///
+/// Only surfaces a match when its distance is at or below a threshold scaled to
+/// the key's length (`max(ceil(len / 3), 1)`), so unrelated keys don't
+/// produce noise while a single-transposition typo like `hots` for `host`
+/// still clears it.
+fn closest_key<'a>(key: &str, candidates: impl Iterator<Item = &'a String>) -> Option<&'a str> {
+    let threshold = key.chars().count().div_ceil(3).max(1);
+
+    candidates
+        .map(|candidate| (candidate.as_str(), levenshtein(key, candidate)))
+        .filter(|&(_, dist)| dist <= threshold)
+        .min_by_key(|&(_, dist)| dist)
+        .map(|(candidate, _)| candidate)
+}
+
+/// Look up `key` in a parsed table, attaching the closest existing key (by
+/// edit distance) to the error when the key is missing.
+///
+/// # Example
+///
+/// ```rust
+/// use libnosr_rs::{document, table};
+/// use libnosr_rs::node::table_get_suggested;
+///
+/// let root = document("{ host: localhost }").unwrap();
+/// let tbl = table(&root).unwrap();
+/// let err = table_get_suggested(&tbl, "hots").unwrap_err();
+/// assert!(err.to_string().contains("host"));
+/// ```
+pub fn table_get_suggested<'a, 'b>(
+    table: &'b HashMap<String, Node<'a>>,
+    key: &str,
+) -> Result<&'b Node<'a>> {
+    table.get(key).ok_or_else(|| {
+        let suggestion = closest_key(key, table.keys()).map(String::from);
+        // The table has already been fully materialized into a plain map, so
+        // there's no span left pointing back at the table's source location.
+        Error::new(
+            ErrorKind::KeyNotFound {
+                key: key.to_string(),
+                suggestion,
+            },
+            Span::new(0, 0),
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -436,6 +1197,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn table_with_options_key_validation_defaults_off() {
+        let source = "{ bad-key: 1 }";
+        let root = Node::new(source, Span::new(0, source.len()));
+        assert!(table(&root).is_ok());
+    }
+
+    #[test]
+    fn table_with_options_rejects_non_identifier_key() {
+        let source = "{ bad-key: 1 }";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let options = crate::lexer::LexerOptions {
+            validate_keys: true,
+            ..Default::default()
+        };
+        let err = table_with_options(&root, options).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidKey(ref k) if k == "bad-key"));
+    }
+
+    #[test]
+    fn table_with_options_normalizes_decomposed_key() {
+        // "café" with "e" + combining acute accent (decomposed).
+        let source = "{ cafe\u{0301}: 1 }";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let options = crate::lexer::LexerOptions {
+            validate_keys: true,
+            ..Default::default()
+        };
+        let tbl = table_with_options(&root, options).unwrap();
+        assert!(tbl.contains_key("caf\u{00E9}"));
+    }
+
+    #[test]
+    fn find_key_returns_matching_value() {
+        let source = "{ name: Alice, age: 30 }";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let name = find_key(&root, "name").unwrap().unwrap();
+        assert_eq!(text(&name).unwrap(), "Alice");
+    }
+
+    #[test]
+    fn find_key_skips_nested_structures_without_decoding_them() {
+        // "a"'s value contains an invalid escape sequence, but since we're
+        // looking for "b" it should never be decoded - only its balanced
+        // span should be skipped over.
+        let source = r#"{ a: { x: "bad\z" }, b: 2 }"#;
+        let root = Node::new(source, Span::new(0, source.len()));
+        let b = find_key(&root, "b").unwrap().unwrap();
+        assert_eq!(uint64(&b).unwrap(), 2);
+    }
+
+    #[test]
+    fn find_key_missing_key_returns_none() {
+        let source = "{ name: Alice }";
+        let root = Node::new(source, Span::new(0, source.len()));
+        assert!(find_key(&root, "missing").unwrap().is_none());
+    }
+
     #[test]
     fn text_unquoted() {
         let source = "hello";
@@ -457,6 +1276,100 @@ mod tests {
         assert_eq!(text(&node).unwrap(), "hello\nworld");
     }
 
+    #[test]
+    fn text_hex_escape() {
+        let source = r#""\x41\x42""#;
+        let node = Node::new(source, Span::new(0, source.len()));
+        assert_eq!(text(&node).unwrap(), "AB");
+    }
+
+    #[test]
+    fn text_hex_escape_rejects_non_ascii_value() {
+        let source = r#""\x80""#;
+        let node = Node::new(source, Span::new(0, source.len()));
+        let err = text(&node).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidHexEscape));
+    }
+
+    #[test]
+    fn text_hex_escape_rejects_non_hex_digit() {
+        let source = r#""\xzz""#;
+        let node = Node::new(source, Span::new(0, source.len()));
+        let err = text(&node).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidHexEscape));
+    }
+
+    #[test]
+    fn text_unicode_escape() {
+        let source = r#""\u{1F30D}""#;
+        let node = Node::new(source, Span::new(0, source.len()));
+        assert_eq!(text(&node).unwrap(), "🌍");
+    }
+
+    #[test]
+    fn text_unicode_escape_short_form() {
+        let source = r#""\u{41}""#;
+        let node = Node::new(source, Span::new(0, source.len()));
+        assert_eq!(text(&node).unwrap(), "A");
+    }
+
+    #[test]
+    fn text_unicode_escape_rejects_empty_braces() {
+        let source = r#""\u{}""#;
+        let node = Node::new(source, Span::new(0, source.len()));
+        let err = text(&node).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidUnicodeEscape));
+    }
+
+    #[test]
+    fn text_unicode_escape_rejects_surrogate() {
+        let source = r#""\u{D800}""#;
+        let node = Node::new(source, Span::new(0, source.len()));
+        let err = text(&node).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidUnicodeEscape));
+    }
+
+    #[test]
+    fn text_unicode_escape_rejects_out_of_range() {
+        let source = r#""\u{110000}""#;
+        let node = Node::new(source, Span::new(0, source.len()));
+        let err = text(&node).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidUnicodeEscape));
+    }
+
+    #[test]
+    fn text_unicode_escape_rejects_unclosed_brace() {
+        let source = r#""\u{41""#;
+        let node = Node::new(source, Span::new(0, source.len()));
+        let err = text(&node).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidUnicodeEscape));
+    }
+
+    #[test]
+    fn text_invalid_escape_has_precise_span() {
+        // `\x` is a recognized two-hex-digit escape introducer, so a space
+        // where the first hex digit should be makes this InvalidHexEscape,
+        // not a plain unrecognized-letter InvalidEscape.
+        let source = r#""Invalid \x escape""#;
+        let node = Node::new(source, Span::new(0, source.len()));
+        let err = text(&node).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidHexEscape));
+        // Span should cover exactly "\x", not the whole string.
+        assert_eq!(err.span, Span::new(source.find("\\x").unwrap(), 2));
+    }
+
+    #[test]
+    fn text_trailing_backslash_before_close_quote() {
+        // A lone backslash immediately before the closing quote: the real
+        // lexer would treat `\"` as an escaped quote and never close the
+        // string here, so this constructs the node directly.
+        let source = "\"trailing\\\"";
+        let node = Node::new(source, Span::new(0, source.len()));
+        let err = text(&node).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::UnterminatedEscape));
+        assert_eq!(err.span, Span::new(source.len() - 2, 1));
+    }
+
     #[test]
     fn parse_uint64() {
         let source = "42";
@@ -470,4 +1383,206 @@ mod tests {
         let node = Node::new(source, Span::new(0, 4));
         assert!((double(&node).unwrap() - 12.5).abs() < 0.0001);
     }
+
+    #[test]
+    fn parse_int64_negative() {
+        let source = "-42";
+        let node = Node::new(source, Span::new(0, source.len()));
+        assert_eq!(int64(&node).unwrap(), -42);
+    }
+
+    #[test]
+    fn parse_rational_reduces_to_lowest_terms() {
+        let source = "-6/8";
+        let node = Node::new(source, Span::new(0, source.len()));
+        assert_eq!(rational(&node).unwrap(), (-3, 4));
+    }
+
+    #[test]
+    fn parse_rational_positive() {
+        let source = "22/7";
+        let node = Node::new(source, Span::new(0, source.len()));
+        assert_eq!(rational(&node).unwrap(), (22, 7));
+    }
+
+    #[test]
+    fn parse_rational_zero_denominator_errors() {
+        let source = "1/0";
+        let node = Node::new(source, Span::new(0, source.len()));
+        let err = rational(&node).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::ZeroDenominator));
+    }
+
+    #[test]
+    fn levenshtein_identical_strings() {
+        assert_eq!(levenshtein("host", "host"), 0);
+    }
+
+    #[test]
+    fn levenshtein_substitution_and_transposition() {
+        assert_eq!(levenshtein("host", "hots"), 2);
+        assert_eq!(levenshtein("cat", "bat"), 1);
+    }
+
+    #[test]
+    fn table_get_suggested_finds_close_key() {
+        let source = "{ host: localhost }";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let tbl = table(&root).unwrap();
+        let err = table_get_suggested(&tbl, "hots").unwrap_err();
+        assert!(matches!(
+            err.kind,
+            ErrorKind::KeyNotFound {
+                suggestion: Some(ref s),
+                ..
+            } if s == "host"
+        ));
+    }
+
+    #[test]
+    fn table_get_suggested_no_suggestion_for_unrelated_key() {
+        let source = "{ host: localhost }";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let tbl = table(&root).unwrap();
+        let err = table_get_suggested(&tbl, "completely_different").unwrap_err();
+        assert!(matches!(
+            err.kind,
+            ErrorKind::KeyNotFound {
+                suggestion: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn table_recover_reports_bad_entry_and_keeps_the_rest() {
+        let source = "{ a: 1, b: :, c: 3 }";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let (entries, errors) = table_recover(&root);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(uint64(entries.get("a").unwrap()).unwrap(), 1);
+        assert_eq!(uint64(entries.get("c").unwrap()).unwrap(), 3);
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn table_recover_handles_multiple_bad_entries() {
+        let source = "{ a: :, b: 2, c: :, d: 4 }";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let (entries, errors) = table_recover(&root);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(uint64(entries.get("b").unwrap()).unwrap(), 2);
+        assert_eq!(uint64(entries.get("d").unwrap()).unwrap(), 4);
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn table_recover_clean_input_matches_table() {
+        let source = "{ a: 1, b: 2 }";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let (entries, errors) = table_recover(&root);
+        assert!(errors.is_empty());
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[test]
+    fn table_recover_not_a_table_reports_error() {
+        let source = "42";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let (entries, errors) = table_recover(&root);
+        assert!(entries.is_empty());
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0].kind, ErrorKind::NotATable));
+    }
+
+    #[test]
+    fn table_recover_reports_consecutive_delimiters() {
+        let source = "{ a: 1,, b: 2, c: 3 }";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let (entries, errors) = table_recover(&root);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(uint64(entries.get("a").unwrap()).unwrap(), 1);
+        assert_eq!(uint64(entries.get("c").unwrap()).unwrap(), 3);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0].kind, ErrorKind::ConsecutiveDelimiters));
+    }
+
+    #[test]
+    fn vector_recover_reports_bad_element_and_keeps_the_rest() {
+        let source = "[1, :, 3]";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let (elements, errors) = vector_recover(&root);
+        assert_eq!(elements.len(), 2);
+        assert_eq!(uint64(&elements[0]).unwrap(), 1);
+        assert_eq!(uint64(&elements[1]).unwrap(), 3);
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn vector_recover_clean_input_matches_vector() {
+        let source = "[1, 2, 3]";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let (elements, errors) = vector_recover(&root);
+        assert!(errors.is_empty());
+        assert_eq!(elements.len(), 3);
+    }
+
+    #[test]
+    fn vector_recover_reports_consecutive_delimiters() {
+        let source = "[1,, 2, 3]";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let (elements, errors) = vector_recover(&root);
+        assert_eq!(elements.len(), 2);
+        assert_eq!(uint64(&elements[0]).unwrap(), 1);
+        assert_eq!(uint64(&elements[1]).unwrap(), 3);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0].kind, ErrorKind::ConsecutiveDelimiters));
+    }
+
+    #[test]
+    fn doc_comment_single_line_is_borrowed_and_stripped() {
+        let source = "{\n;;; The server's hostname.\nhost: localhost\n}";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let tbl = table(&root).unwrap();
+        let host = tbl.get("host").unwrap();
+        assert_eq!(doc_comment(host).unwrap(), "The server's hostname.");
+    }
+
+    #[test]
+    fn doc_comment_multiple_contiguous_lines_are_joined() {
+        let source = "{\n;;; Line one.\n;;; Line two.\nhost: localhost\n}";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let tbl = table(&root).unwrap();
+        let host = tbl.get("host").unwrap();
+        assert_eq!(doc_comment(host).unwrap(), "Line one.\nLine two.");
+    }
+
+    #[test]
+    fn doc_comment_stops_at_blank_line() {
+        let source = "{\n;;; Not attached.\n\nhost: localhost\n}";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let tbl = table(&root).unwrap();
+        let host = tbl.get("host").unwrap();
+        assert!(doc_comment(host).is_none());
+    }
+
+    #[test]
+    fn doc_comment_missing_returns_none() {
+        let source = "{\nhost: localhost\n}";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let tbl = table(&root).unwrap();
+        let host = tbl.get("host").unwrap();
+        assert!(doc_comment(host).is_none());
+    }
+
+    #[test]
+    fn vector_recover_nested_structure_after_bad_element_is_preserved() {
+        let source = "[:, [1, 2]]";
+        let root = Node::new(source, Span::new(0, source.len()));
+        let (elements, errors) = vector_recover(&root);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(elements.len(), 1);
+        let nested = vector(&elements[0]).unwrap();
+        assert_eq!(nested.len(), 2);
+    }
 }
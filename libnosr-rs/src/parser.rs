@@ -4,16 +4,27 @@
 //! The actual parsing of tables, vectors, and values happens lazily
 //! when you navigate the tree.
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::node::Node;
 use crate::span::Span;
 
+/// Maximum number of errors `document_all` will collect before giving up on
+/// reporting more of them. Later errors are still recovered from, just not
+/// pushed onto the returned `Vec`, so a single bad token can't cascade into
+/// hundreds of spurious reports.
+const MAX_ERRORS: usize = 100;
+
 /// Parse a nosr document from a string.
 ///
 /// This creates a root node representing the entire document.
 /// The document is not fully parsed at this point - parsing happens
 /// lazily as you navigate the tree.
 ///
+/// This is a thin wrapper around [`document_all`] that returns only the
+/// first error it collected instead of every one. Use `document_all` for editor or
+/// linter tooling that wants to report everything wrong with a document in
+/// a single pass.
+///
 /// # Example
 ///
 /// ```rust
@@ -23,40 +34,154 @@ use crate::span::Span;
 /// let root = document(source).expect("failed to parse");
 /// ```
 pub fn document<'a>(source: &'a str) -> Result<Node<'a>> {
+    document_with_options(source, crate::lexer::LexerOptions::default())
+}
+
+/// Parse a nosr document, applying `options` to the underlying lexer.
+///
+/// See [`document`] for the general behavior and [`LexerOptions`] for what
+/// each option changes.
+///
+/// # Example
+///
+/// ```rust
+/// use libnosr_rs::lexer::LexerOptions;
+/// use libnosr_rs::parser::document_with_options;
+///
+/// let source = "{\n  name:\n    Alice\n}";
+/// let options = LexerOptions {
+///     suppress_significant_newlines: true,
+///     ..Default::default()
+/// };
+/// let root = document_with_options(source, options).expect("failed to parse");
+/// ```
+///
+/// [`LexerOptions`]: crate::lexer::LexerOptions
+pub fn document_with_options<'a>(
+    source: &'a str,
+    options: crate::lexer::LexerOptions,
+) -> Result<Node<'a>> {
+    let (node, mut errors) = document_all_with_options(source, options);
+    if !errors.is_empty() {
+        return Err(errors.remove(0));
+    }
+    Ok(node.unwrap_or_else(|| Node::new(source, Span::new(0, 0))))
+}
+
+/// Parse a nosr document, collecting every lexing and structural error
+/// instead of bailing out at the first one.
+///
+/// Modeled on the TOML parser's `errors: Vec<ParserError>` field: when the
+/// lexer hits an error such as an unclosed string or an unclosed comment,
+/// the error is recorded and the scan *synchronizes* - it
+/// skips forward until the next top-level delimiter (a newline or comma at
+/// the depth the error occurred at, or a closing brace/bracket that matches
+/// an enclosing structure) and resumes from there. This lets editor and
+/// linter tooling surface every problem in a document in one pass instead of
+/// forcing a fix-rerun loop.
+///
+/// Brace/bracket nesting depth is tracked only to decide where recovery
+/// resumes; an unbalanced brace or bracket is not itself reported as an
+/// error.
+///
+/// Returns the best-effort root node spanning whatever content was
+/// successfully scanned (`None` if the document had no content at all),
+/// alongside every error encountered. The number of reported errors is
+/// capped at an internal limit to avoid cascades.
+///
+/// # Example
+///
+/// ```rust
+/// use libnosr_rs::document_all;
+///
+/// let source = "{ a: 1, b: \"unclosed }";
+/// let (node, errors) = document_all(source);
+/// assert!(node.is_some());
+/// assert!(!errors.is_empty());
+/// ```
+pub fn document_all(source: &str) -> (Option<Node<'_>>, Vec<Error>) {
+    document_all_with_options(source, crate::lexer::LexerOptions::default())
+}
+
+/// Parse a nosr document collecting every error, applying `options` to the
+/// underlying lexer. See [`document_all`] for the general behavior.
+pub fn document_all_with_options(
+    source: &str,
+    options: crate::lexer::LexerOptions,
+) -> (Option<Node<'_>>, Vec<Error>) {
     use crate::lexer::{Lexer, TokenKind};
 
-    // Use the lexer to find the first real token (skipping comments, whitespace, and newlines)
-    let mut lexer = Lexer::new(source);
+    let mut lexer = Lexer::with_options(source, options);
+    let mut errors = Vec::new();
+    let mut depth: i64 = 0;
+    let mut start_pos: Option<usize> = None;
+    let mut last_span = Span::new(0, 0);
 
-    // Skip newlines and get the first real token
-    let mut first_token = lexer.next_token()?;
-    while first_token.kind == TokenKind::Newline {
-        first_token = lexer.next_token()?;
+    loop {
+        let depth_before = depth;
+        match lexer.next_token() {
+            Ok(tok) => {
+                match tok.kind {
+                    TokenKind::Eof => break,
+                    TokenKind::LeftBrace | TokenKind::LeftBracket => depth += 1,
+                    TokenKind::RightBrace | TokenKind::RightBracket => depth -= 1,
+                    _ => {}
+                }
+                if tok.kind != TokenKind::Newline {
+                    start_pos.get_or_insert(tok.span.start);
+                    last_span = tok.span;
+                }
+            }
+            Err(err) => {
+                push_capped(&mut errors, err);
+                synchronize(&mut lexer, &mut depth, depth_before, &mut errors);
+            }
+        }
     }
 
-    if first_token.kind == TokenKind::Eof {
-        // Empty document - return empty span
-        return Ok(Node::new(source, Span::new(0, 0)));
+    let node =
+        start_pos.map(|start| Node::new(source, Span::new(start, last_span.end() - start)));
+    (node, errors)
+}
+
+/// Push an error onto the list unless the cap has already been reached.
+fn push_capped(errors: &mut Vec<Error>, err: Error) {
+    if errors.len() < MAX_ERRORS {
+        errors.push(err);
     }
+}
 
-    // Find the last token to determine the end of the document
-    let start_pos = first_token.span.start;
-    let mut last_span = first_token.span;
+/// Skip tokens until the next top-level delimiter at `target_depth` (a
+/// newline or comma), or a closing brace/bracket that drops below it.
+///
+/// Depth is tracked across the whole synchronization so recovery resumes at
+/// the right nesting level rather than stopping on a delimiter that belongs
+/// to a deeper, still-broken structure.
+fn synchronize(
+    lexer: &mut crate::lexer::Lexer,
+    depth: &mut i64,
+    target_depth: i64,
+    errors: &mut Vec<Error>,
+) {
+    use crate::lexer::TokenKind;
 
-    // Continue through all tokens to find the end
     loop {
-        let tok = lexer.next_token()?;
-        if tok.kind == TokenKind::Eof {
-            break;
-        }
-        // Only update for non-newline tokens (newlines at end shouldn't count)
-        if tok.kind != TokenKind::Newline {
-            last_span = tok.span;
+        match lexer.next_token() {
+            Ok(tok) => match tok.kind {
+                TokenKind::Eof => return,
+                TokenKind::LeftBrace | TokenKind::LeftBracket => *depth += 1,
+                TokenKind::RightBrace | TokenKind::RightBracket => {
+                    *depth -= 1;
+                    if *depth < target_depth {
+                        return;
+                    }
+                }
+                TokenKind::Newline | TokenKind::Comma if *depth == target_depth => return,
+                _ => {}
+            },
+            Err(err) => push_capped(errors, err),
         }
     }
-
-    let span = Span::new(start_pos, last_span.end() - start_pos);
-    Ok(Node::new(source, span))
 }
 
 #[cfg(test)]
@@ -84,4 +209,34 @@ mod tests {
         let node = document(source).unwrap();
         assert_eq!(text(&node).unwrap(), "hello");
     }
+
+    #[test]
+    fn document_all_no_errors_matches_document() {
+        let source = "{ name: Alice }";
+        let (node, errors) = document_all(source);
+        assert!(errors.is_empty());
+        assert_eq!(node.unwrap().raw(), source);
+    }
+
+    #[test]
+    fn document_all_recovers_from_unclosed_string_and_keeps_going() {
+        let source = "{ a: \"unclosed, b: 2 }";
+        let (node, errors) = document_all(source);
+        assert_eq!(errors.len(), 1);
+        assert!(node.is_some());
+    }
+
+    #[test]
+    fn document_all_empty_source_has_no_node() {
+        let (node, errors) = document_all("");
+        assert!(node.is_none());
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn document_returns_first_error() {
+        let source = "{ a: \"unclosed, b: 2 }";
+        let err = document(source).unwrap_err();
+        assert!(matches!(err.kind, crate::error::ErrorKind::UnclosedString));
+    }
 }
@@ -16,12 +16,38 @@ pub struct Span {
     pub len: usize,
 }
 
+/// A 1-based line and column position in a source document.
+///
+/// Columns are counted in Unicode scalar values (chars), not bytes, so they
+/// line up correctly when printed under multibyte UTF-8 text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineCol {
+    /// The 1-based line number.
+    pub line: usize,
+    /// The 1-based column number.
+    pub col: usize,
+}
+
 impl Span {
     /// Create a new span from a start position and length.
     pub fn new(start: usize, len: usize) -> Self {
         Self { start, len }
     }
 
+    /// Resolve this span's start position to a 1-based line and column in
+    /// `source`.
+    ///
+    /// Convenience wrapper around [`SourceMap`] for one-off lookups; if
+    /// you're resolving many spans against the same source, build a
+    /// `SourceMap` once and call [`SourceMap::location`] instead.
+    pub fn line_col(&self, source: &str) -> LineCol {
+        let loc = SourceMap::new(source).location(source, *self);
+        LineCol {
+            line: loc.line,
+            col: loc.col,
+        }
+    }
+
     /// Get the end position (exclusive) of this span.
     pub fn end(&self) -> usize {
         self.start + self.len
@@ -52,6 +78,66 @@ impl Span {
     }
 }
 
+/// A resolved source location: a 1-based line/column plus the full text of
+/// that line, ready to render as a caret diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation<'a> {
+    /// The 1-based line number.
+    pub line: usize,
+    /// The 1-based column number, counted in Unicode scalar values.
+    pub col: usize,
+    /// The full text of the line containing the location, without its
+    /// trailing newline.
+    pub line_text: &'a str,
+}
+
+/// Precomputed line-start offsets for a source document.
+///
+/// `Span::line_col` rebuilds its line-start table from scratch on every
+/// call, which is fine for a single error but wasteful when rendering many
+/// diagnostics against the same document (e.g. the `Vec<Error>` returned by
+/// [`crate::node::table_recover`]). Build a `SourceMap` once per document
+/// and call [`SourceMap::location`] for each span instead.
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+    line_starts: Vec<usize>,
+}
+
+impl SourceMap {
+    /// Precompute the line-start offsets for `source`.
+    pub fn new(source: &str) -> Self {
+        let line_starts = source.match_indices('\n').map(|(i, _)| i + 1).collect();
+        Self { line_starts }
+    }
+
+    /// Resolve `span`'s start position to a line, column, and line text in
+    /// `source`.
+    ///
+    /// `source` must be the same document this map was built from; passing
+    /// a different one produces nonsensical results rather than a panic.
+    pub fn location<'a>(&self, source: &'a str, span: Span) -> SourceLocation<'a> {
+        let line = self.line_starts.partition_point(|&start| start <= span.start);
+        let line_start = if line == 0 {
+            0
+        } else {
+            self.line_starts[line - 1]
+        };
+        let line_end = self
+            .line_starts
+            .get(line)
+            .copied()
+            .unwrap_or(source.len());
+        let col = source[line_start..span.start].chars().count() + 1;
+        let line_text = source[line_start..line_end].trim_end_matches('\n');
+
+        SourceLocation {
+            line: line + 1,
+            col,
+            line_text,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,4 +161,65 @@ mod tests {
         assert_eq!(merged.start, 5);
         assert_eq!(merged.len, 7); // 5..12
     }
+
+    #[test]
+    fn line_col_first_line() {
+        let source = "hello world";
+        let span = Span::new(6, 5);
+        let lc = span.line_col(source);
+        assert_eq!(lc.line, 1);
+        assert_eq!(lc.col, 7);
+    }
+
+    #[test]
+    fn line_col_later_line() {
+        let source = "first\nsecond\nthird";
+        let span = Span::new(13, 5); // "third"
+        let lc = span.line_col(source);
+        assert_eq!(lc.line, 3);
+        assert_eq!(lc.col, 1);
+    }
+
+    #[test]
+    fn line_col_counts_chars_not_bytes() {
+        let source = "café: value";
+        // "value" starts after the multibyte é (2 bytes but 1 char)
+        let byte_start = source.find("value").unwrap();
+        let span = Span::new(byte_start, 5);
+        let lc = span.line_col(source);
+        assert_eq!(lc.line, 1);
+        assert_eq!(lc.col, 7); // c,a,f,é,:,<space> then "value"
+    }
+
+    #[test]
+    fn source_map_location_matches_line_col() {
+        let source = "first\nsecond\nthird";
+        let span = Span::new(13, 5); // "third"
+        let map = SourceMap::new(source);
+        let loc = map.location(source, span);
+        assert_eq!(loc.line, 3);
+        assert_eq!(loc.col, 1);
+        assert_eq!(loc.line_text, "third");
+    }
+
+    #[test]
+    fn source_map_location_includes_full_line_text() {
+        let source = "first\nsecond\nthird";
+        let span = Span::new(6, 3); // "sec" within "second"
+        let map = SourceMap::new(source);
+        let loc = map.location(source, span);
+        assert_eq!(loc.line, 2);
+        assert_eq!(loc.line_text, "second");
+    }
+
+    #[test]
+    fn source_map_reused_across_multiple_spans() {
+        let source = "a\nbb\nccc";
+        let map = SourceMap::new(source);
+        let first = map.location(source, Span::new(0, 1));
+        let third = map.location(source, Span::new(5, 3));
+        assert_eq!(first.line, 1);
+        assert_eq!(third.line, 3);
+        assert_eq!(third.line_text, "ccc");
+    }
 }
@@ -2,6 +2,9 @@
 //!
 //! These tests verify the parser against examples from the nosr specification.
 
+use libnosr_rs::lexer::LexerOptions;
+use libnosr_rs::node::{table_with_options, vector_with_options};
+use libnosr_rs::parser::document_with_options;
 use libnosr_rs::{document, double, table, text, uint64, vector};
 
 #[test]
@@ -319,6 +322,28 @@ fn test_mixed_delimiters() {
     assert_eq!(text(&v[3]).expect("text failed"), "four");
 }
 
+#[test]
+fn test_suppressed_newlines_allow_multiline_values() {
+    let options = LexerOptions {
+        suppress_significant_newlines: true,
+        ..Default::default()
+    };
+
+    let table_source = "{\n  name:\n    \"Alice\"\n}";
+    let root = document_with_options(table_source, options).expect("parse failed");
+    let tbl = table_with_options(&root, options).expect("table failed");
+    assert_eq!(
+        text(tbl.get("name").expect("name not found")).expect("text failed"),
+        "Alice"
+    );
+
+    let vector_source = "[\n  one,\n  two\n]";
+    let root = document_with_options(vector_source, options).expect("parse failed");
+    let v = vector_with_options(&root, options).expect("vector failed");
+    assert_eq!(text(&v[0]).expect("text failed"), "one");
+    assert_eq!(text(&v[1]).expect("text failed"), "two");
+}
+
 #[test]
 fn test_large_integer() {
     let source = "18446744073709551615"; // Max u64